@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::grid::GridLike;
+use crate::point::{Neighbourhood, Point};
+use crate::rect::Rect;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Update {
+    // every cell sees the previous generation
+    Synchronous,
+    // cells are updated row by row and see the already updated cells before them
+    InPlace,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    // a generation changes nothing
+    FixedPoint,
+    // at most this many, a fixed point still stops earlier
+    Generations(usize),
+    // the grid is back in a state it was in before
+    Cycle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    // first generation of the repeating part, 0 is the initial grid
+    pub start: usize,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome<G> {
+    pub grid: G,
+    // changed cells per generation. A generation that changes nothing isn't
+    // counted, so this is also the number of generations that made progress.
+    pub changes: Vec<usize>,
+    pub cycle: Option<Cycle>,
+}
+
+// Runs `rule(cell, neighbours)` over every cell of a grid, generation after
+// generation. Defaults to the Moore neighbourhood, synchronous updates and
+// stopping at a fixed point.
+pub struct Automaton<'a, R> {
+    rule: R,
+    neighbourhood: Neighbourhood<'a>,
+    update: Update,
+    stop: Stop,
+}
+
+impl<'a, R> Automaton<'a, R> {
+    pub fn new(rule: R) -> Self {
+        Automaton {
+            rule,
+            neighbourhood: Neighbourhood::Moore,
+            update: Update::Synchronous,
+            stop: Stop::FixedPoint,
+        }
+    }
+
+    pub fn neighbourhood(mut self, neighbourhood: Neighbourhood<'a>) -> Self {
+        self.neighbourhood = neighbourhood;
+        self
+    }
+
+    pub fn update(mut self, update: Update) -> Self {
+        self.update = update;
+        self
+    }
+
+    pub fn stop(mut self, stop: Stop) -> Self {
+        self.stop = stop;
+        self
+    }
+
+    pub fn run<G>(&self, grid: G) -> Outcome<G>
+    where
+        G: GridLike + Clone,
+        G::Cell: Hash + Eq,
+        R: Fn(G::Cell, &[G::Cell]) -> G::Cell,
+    {
+        let mut outcome = Outcome {
+            grid,
+            changes: Vec::new(),
+            cycle: None,
+        };
+        let Some(bounds) = outcome.grid.bounds() else {
+            return outcome;
+        };
+        let mut cells = Cells::new(bounds, &self.neighbourhood);
+
+        let mut seen: HashMap<Vec<G::Cell>, usize> = HashMap::new();
+        if self.stop == Stop::Cycle {
+            seen.insert(Self::state(&outcome.grid), 0);
+        }
+
+        loop {
+            if self.stop == Stop::Generations(outcome.changes.len()) {
+                break;
+            }
+
+            let changed = self.step(&mut outcome.grid, &mut cells);
+            if changed == 0 {
+                if self.stop == Stop::Cycle {
+                    let generation = outcome.changes.len();
+                    outcome.cycle = Some(Cycle {
+                        start: generation,
+                        length: 1,
+                    });
+                }
+                break;
+            }
+            outcome.changes.push(changed);
+
+            if self.stop == Stop::Cycle {
+                let generation = outcome.changes.len();
+                if let Some(&start) = seen.get(&Self::state(&outcome.grid)) {
+                    outcome.cycle = Some(Cycle {
+                        start,
+                        length: generation - start,
+                    });
+                    break;
+                }
+                seen.insert(Self::state(&outcome.grid), generation);
+            }
+        }
+
+        outcome
+    }
+
+    // Applies the rule once to every active cell and returns how many of them changed
+    fn step<G>(&self, grid: &mut G, cells: &mut Cells) -> usize
+    where
+        G: GridLike + Clone,
+        G::Cell: PartialEq,
+        R: Fn(G::Cell, &[G::Cell]) -> G::Cell,
+    {
+        let previous = match self.update {
+            Update::Synchronous => Some(grid.clone()),
+            Update::InPlace => None,
+        };
+
+        let mut neighbours = Vec::new();
+        let mut changed = 0;
+        for i in 0..cells.points.len() {
+            if !cells.active[i] {
+                continue;
+            }
+            cells.active[i] = false;
+
+            let point = cells.points[i];
+            let (cell, next) = {
+                let source = previous.as_ref().unwrap_or(grid);
+                let cell = source.get(point).unwrap();
+                neighbours.clear();
+                neighbours.extend(
+                    source
+                        .neighbours_in(point, &self.neighbourhood)
+                        .map(|(_, c)| c),
+                );
+                (cell, (self.rule)(cell, &neighbours))
+            };
+            if next != cell {
+                grid.set(point, next);
+                changed += 1;
+                cells.wake(point, self.update, grid);
+            }
+        }
+
+        if self.update == Update::Synchronous {
+            std::mem::swap(&mut cells.active, &mut cells.woken);
+        }
+        changed
+    }
+
+    fn state<G: GridLike>(grid: &G) -> Vec<G::Cell> {
+        grid.cells().map(|(_, cell)| cell).collect()
+    }
+}
+
+// The points of the grid with the cells worth evaluating. A pure rule gives the
+// same result again unless the cell or one of its neighbours changed, so only
+// those are woken up.
+struct Cells {
+    bounds: Rect,
+    points: Vec<Point>,
+    active: Vec<bool>,
+    // cells woken for the next generation by synchronous updates
+    woken: Vec<bool>,
+    // offsets of the cells that have a given cell as their neighbour
+    dependants: Vec<Point>,
+}
+
+impl Cells {
+    fn new(bounds: Rect, neighbourhood: &Neighbourhood) -> Self {
+        let points: Vec<Point> = bounds.points().collect();
+        Cells {
+            bounds,
+            active: vec![true; points.len()],
+            woken: vec![false; points.len()],
+            points,
+            dependants: neighbourhood
+                .offsets()
+                .iter()
+                .map(|&offset| -offset)
+                .collect(),
+        }
+    }
+
+    // In place, cells later in the current sweep see the change right away and
+    // the earlier ones on the next sweep
+    fn wake<G: GridLike>(&mut self, point: Point, update: Update, grid: &G) {
+        let bounds = self.bounds;
+        let index =
+            |p: Point| ((p.y - bounds.min.y) * bounds.size().x + p.x - bounds.min.x) as usize;
+        let flags = match update {
+            Update::Synchronous => &mut self.woken,
+            Update::InPlace => &mut self.active,
+        };
+
+        flags[index(point)] = true;
+        for &offset in &self.dependants {
+            if let Some(dependant) = grid.normalise(point + offset) {
+                flags[index(dependant)] = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::grid::testing::walls;
+
+    fn life(alive: bool, neighbours: &[bool]) -> bool {
+        let count = neighbours.iter().filter(|&&n| n).count();
+        count == 3 || (alive && count == 2)
+    }
+
+    #[test]
+    fn test_blinker_cycle() {
+        let blinker = walls(".....\n..#..\n..#..\n..#..\n.....");
+        let outcome = Automaton::new(life).stop(Stop::Cycle).run(blinker.clone());
+
+        assert_eq!(
+            outcome.cycle,
+            Some(Cycle {
+                start: 0,
+                length: 2
+            })
+        );
+        assert_eq!(outcome.changes, vec![4, 4]);
+        assert_eq!(outcome.grid, blinker);
+    }
+
+    #[test]
+    fn test_glider_on_torus() {
+        // a glider moves one cell diagonally every 4 generations and gets back
+        // to where it started after crossing the 5x5 torus
+        let glider = walls(".#...\n..#..\n###..\n.....\n.....").wrapping();
+        let outcome = Automaton::new(life).stop(Stop::Cycle).run(glider.clone());
+
+        assert_eq!(
+            outcome.cycle,
+            Some(Cycle {
+                start: 0,
+                length: 20
+            })
+        );
+        assert_eq!(outcome.grid, glider);
+    }
+
+    #[test]
+    fn test_generations() {
+        let blinker = walls(".....\n..#..\n..#..\n..#..\n.....");
+        let outcome = Automaton::new(life)
+            .stop(Stop::Generations(3))
+            .run(blinker.clone());
+
+        assert_eq!(outcome.changes.len(), 3);
+        assert_eq!(outcome.grid, blinker.transpose());
+        assert_eq!(outcome.cycle, None);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        // a block never changes, the lone cell dies in the first generation
+        let grid = walls("##...\n##...\n.....\n....#");
+        let outcome = Automaton::new(life).run(grid);
+
+        assert_eq!(outcome.changes, vec![1]);
+        assert_eq!(outcome.grid.count(|&alive| alive), 4);
+
+        let still = Automaton::new(life).stop(Stop::Cycle).run(outcome.grid);
+        assert_eq!(
+            still.cycle,
+            Some(Cycle {
+                start: 0,
+                length: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_in_place() {
+        // every cell copies its left neighbour. Synchronously the pattern moves one
+        // step per generation, in place the first cell spreads along the whole row.
+        let shift = |cell: u8, neighbours: &[u8]| neighbours.first().copied().unwrap_or(cell);
+        let left = [Point::new(-1, 0)];
+        let row = Grid::new(vec![vec![1, 0, 0, 0]]);
+
+        let synchronous = Automaton::new(shift)
+            .neighbourhood(Neighbourhood::Custom(&left))
+            .stop(Stop::Generations(1))
+            .run(row.clone());
+        assert_eq!(synchronous.grid, Grid::new(vec![vec![1, 1, 0, 0]]));
+
+        let in_place = Automaton::new(shift)
+            .neighbourhood(Neighbourhood::Custom(&left))
+            .update(Update::InPlace)
+            .stop(Stop::Generations(1))
+            .run(row);
+        assert_eq!(in_place.grid, Grid::new(vec![vec![1, 1, 1, 1]]));
+        assert_eq!(in_place.changes, vec![3]);
+    }
+
+    #[test]
+    fn test_matches_full_sweeps() {
+        // only re-evaluating cells next to a change must not change the result
+        let mut rng = crate::rng::Rng::new(7);
+        let grid = Grid::from_fn(Point::new(20, 15), |_| rng.chance(0.4));
+
+        let mut expected = grid.clone();
+        for generations in 1..=12 {
+            let previous = expected.clone();
+            expected = Grid::from_fn(previous.size(), |p| {
+                let neighbours: Vec<bool> = previous.neighbours(p).map(|(_, &c)| c).collect();
+                life(previous[p], &neighbours)
+            });
+
+            let outcome = Automaton::new(life)
+                .stop(Stop::Generations(generations))
+                .run(grid.clone());
+            assert_eq!(outcome.grid, expected, "generation {}", generations);
+        }
+
+        let mut expected = grid.clone();
+        for p in grid.size().iter_inside() {
+            let neighbours: Vec<bool> = expected.neighbours(p).map(|(_, &c)| c).collect();
+            expected[p] = life(expected[p], &neighbours);
+        }
+        let outcome = Automaton::new(life)
+            .update(Update::InPlace)
+            .stop(Stop::Generations(1))
+            .run(grid);
+        assert_eq!(outcome.grid, expected);
+    }
+}
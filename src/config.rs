@@ -0,0 +1,83 @@
+use std::path::Path;
+
+pub const CONFIG_FILE: &str = "aoc.conf";
+
+// Defaults for the command line, read from `key = value` lines with `#` comments
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    pub year: Option<u32>,
+}
+
+impl Config {
+    // A missing file is an empty config, a malformed one is an error
+    pub fn load(path: &Path) -> Config {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Config::parse(&content),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Config::default(),
+            Err(error) => panic!("Failed to read {}: {}", path.display(), error),
+        }
+    }
+
+    pub fn parse(content: &str) -> Config {
+        let mut config = Config::default();
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .unwrap_or_else(|| panic!("Invalid config line: {}", line));
+            match key.trim() {
+                "year" => {
+                    config.year = Some(
+                        value
+                            .trim()
+                            .parse()
+                            .unwrap_or_else(|_| panic!("Invalid year: {}", value.trim())),
+                    )
+                }
+                key => panic!("Unknown config key: {}", key),
+            }
+        }
+
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Config::parse(""), Config::default());
+        assert_eq!(
+            Config::parse("# next season\n\n  year = 2026  # not out yet\n").year,
+            Some(2026)
+        );
+        assert_eq!(Config::parse("year=2024\nyear=2025").year, Some(2025));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown config key: day")]
+    fn test_parse_unknown_key() {
+        Config::parse("day = 1");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid year")]
+    fn test_parse_invalid_year() {
+        Config::parse("year = next");
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        assert_eq!(
+            Config::load(Path::new("does/not/exist.conf")),
+            Config::default()
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::point::Point;
+use crate::tasks::task::TaskInput;
+
+// Compass directions on screen: north is up and y grows downwards (south)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    // clockwise, starting from north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    // The move of a single step in this direction
+    pub const fn offset(&self) -> Point {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        Point { x, y }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        let offset = self.offset();
+        offset.x != 0 && offset.y != 0
+    }
+
+    // Turns by `eighths` of a full turn, clockwise when positive
+    pub fn rotate(&self, eighths: i64) -> Direction {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap() as i64;
+        Self::ALL[(index + eighths).rem_euclid(8) as usize]
+    }
+
+    // 90° turns
+    pub fn turn_left(&self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate(4)
+    }
+
+    // Arrows (^v<>), U/D/L/R and N/E/S/W
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' | 'U' | 'N' => Some(Direction::North),
+            'v' | 'D' | 'S' => Some(Direction::South),
+            '<' | 'L' | 'W' => Some(Direction::West),
+            '>' | 'R' | 'E' => Some(Direction::East),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Point {
+        direction.offset()
+    }
+}
+
+// Any single character from_char accepts, or a diagonal as NE/SE/SW/NW
+impl TaskInput for Direction {
+    fn from_str(s: &str) -> Self {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next())
+            && let Some(direction) = Direction::from_char(c)
+        {
+            return direction;
+        }
+        match s {
+            "NE" => Direction::NorthEast,
+            "SE" => Direction::SouthEast,
+            "SW" => Direction::SouthWest,
+            "NW" => Direction::NorthWest,
+            _ => panic!("Invalid direction: {}", s),
+        }
+    }
+}
+
+// Something standing on a point and facing a direction, e.g. a guard on patrol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Heading {
+    pub position: Point,
+    pub direction: Direction,
+}
+
+impl Heading {
+    pub fn new(position: Point, direction: Direction) -> Self {
+        Heading {
+            position,
+            direction,
+        }
+    }
+
+    // The point one step forward
+    pub fn ahead(&self) -> Point {
+        self.position + self.direction.offset()
+    }
+
+    pub fn step(&self) -> Heading {
+        self.forward(1)
+    }
+
+    pub fn forward(&self, steps: i64) -> Heading {
+        Heading {
+            position: self.position + self.direction.offset() * steps,
+            direction: self.direction,
+        }
+    }
+
+    pub fn turn_left(&self) -> Heading {
+        Heading {
+            position: self.position,
+            direction: self.direction.turn_left(),
+        }
+    }
+
+    pub fn turn_right(&self) -> Heading {
+        Heading {
+            position: self.position,
+            direction: self.direction.turn_right(),
+        }
+    }
+
+    pub fn turn_around(&self) -> Heading {
+        Heading {
+            position: self.position,
+            direction: self.direction.opposite(),
+        }
+    }
+
+    // The headings after each step forward, without turning
+    pub fn walk(&self) -> impl Iterator<Item = Heading> + use<> {
+        let start = *self;
+        std::iter::successors(Some(start.step()), |heading| Some(heading.step()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthWest.turn_right(), Direction::NorthEast);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        assert_eq!(Direction::West.rotate(1), Direction::NorthWest);
+        assert_eq!(Direction::North.rotate(-9), Direction::NorthWest);
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+            // turning matches rotating the offset about the origin
+            assert_eq!(
+                direction.turn_right().offset(),
+                direction.offset().rotate_clockwise()
+            );
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Point::from(Direction::North), Point::new(0, -1));
+        assert_eq!(Direction::SouthWest.offset(), Point::new(-1, 1));
+        assert!(Direction::SouthWest.is_diagonal());
+        assert!(Direction::ORTHOGONAL.iter().all(|d| !d.is_diagonal()));
+
+        let neighbours: Vec<Point> = Point::new(0, 0).neighbours().collect();
+        assert!(
+            Direction::ALL
+                .iter()
+                .all(|d| neighbours.contains(&d.offset()))
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        for (chars, direction) in [
+            ("^UN", Direction::North),
+            ("vDS", Direction::South),
+            ("<LW", Direction::West),
+            (">RE", Direction::East),
+        ] {
+            assert!(
+                chars
+                    .chars()
+                    .all(|c| Direction::from_char(c) == Some(direction))
+            );
+        }
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::from_str("NE"), Direction::NorthEast);
+        assert_eq!(Direction::from_str("<"), Direction::West);
+
+        let arrows: Grid<Direction> = Grid::from_str("^>\nv<");
+        assert_eq!(arrows[Point::new(1, 1)], Direction::West);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid direction")]
+    fn test_parse_invalid() {
+        Direction::from_str("NN");
+    }
+
+    #[test]
+    fn test_heading() {
+        let heading = Heading::new(Point::new(2, 2), Direction::North);
+
+        assert_eq!(heading.ahead(), Point::new(2, 1));
+        assert_eq!(heading.forward(3).position, Point::new(2, -1));
+        assert_eq!(heading.turn_right().step().position, Point::new(3, 2));
+        assert_eq!(heading.turn_around().direction, Direction::South);
+
+        // four right turns with a step each walk around a square
+        let mut walker = heading;
+        for _ in 0..4 {
+            walker = walker.step().turn_right();
+        }
+        assert_eq!(walker, heading);
+
+        let path: Vec<Point> = heading.walk().take(3).map(|h| h.position).collect();
+        assert_eq!(
+            path,
+            vec![Point::new(2, 1), Point::new(2, 0), Point::new(2, -1)]
+        );
+    }
+}
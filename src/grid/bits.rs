@@ -0,0 +1,318 @@
+use std::ops::{BitAnd, BitOr, BitXor, BitXorAssign, Not};
+
+use super::{Grid, GridLike};
+use crate::point::Point;
+use crate::rect::Rect;
+
+// A grid of booleans packed 64 cells per word. Every row starts on a new word,
+// bit i of word w in a row is the cell at x = w * 64 + i. Bits past the width
+// are always zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: i64,
+    height: i64,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(size: Point) -> Self {
+        assert!(size.x >= 0 && size.y >= 0, "Grid size cannot be negative");
+        let words_per_row = (size.x as usize).div_ceil(64);
+        BitGrid {
+            width: size.x,
+            height: size.y,
+            words_per_row,
+            words: vec![0; words_per_row * size.y as usize],
+        }
+    }
+
+    // Sets the cells of `grid` accepted by `predicate`
+    pub fn from_grid<T>(grid: &Grid<T>, predicate: impl Fn(&T) -> bool) -> Self {
+        let mut bits = BitGrid::new(grid.size());
+        for (point, cell) in grid.iter() {
+            if predicate(cell) {
+                bits.set(point, true);
+            }
+        }
+        bits
+    }
+
+    pub fn size(&self) -> Point {
+        Point::new(self.width, self.height)
+    }
+
+    pub fn get(&self, point: Point) -> Option<bool> {
+        let (word, bit) = self.locate(point)?;
+        Some(self.words[word] >> bit & 1 == 1)
+    }
+
+    // Does nothing outside of the grid, same as Grid::replace
+    pub fn set(&mut self, point: Point, value: bool) {
+        if let Some((word, bit)) = self.locate(point) {
+            if value {
+                self.words[word] |= 1 << bit;
+            } else {
+                self.words[word] &= !(1 << bit);
+            }
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn row_count_ones(&self, y: i64) -> usize {
+        self.row(y).iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    // Cells with at least `k` set cells among their 8 neighbours. The counts are
+    // kept bit-sliced, one word per binary digit, so 64 cells are added at once.
+    pub fn neighbours_at_least(&self, k: u32) -> BitGrid {
+        let mut result = BitGrid::new(self.size());
+        if k == 0 {
+            return !&result;
+        }
+
+        let empty = vec![0; self.words_per_row];
+        for y in 0..self.height {
+            let above = if y > 0 { self.row(y - 1) } else { &empty };
+            let below = if y + 1 < self.height {
+                self.row(y + 1)
+            } else {
+                &empty
+            };
+            let row = self.row(y);
+
+            for w in 0..self.words_per_row {
+                // counts go up to 8, so 4 binary digits
+                let mut digits = [0u64; 4];
+                let mut add = |bits: u64| {
+                    let mut carry = bits;
+                    for digit in digits.iter_mut() {
+                        let sum = *digit ^ carry;
+                        carry &= *digit;
+                        *digit = sum;
+                    }
+                };
+
+                for line in [above, below] {
+                    add(line[w]);
+                    add(Self::shifted_left(line, w));
+                    add(Self::shifted_right(line, w));
+                }
+                add(Self::shifted_left(row, w));
+                add(Self::shifted_right(row, w));
+
+                let at_least = (k..=8).fold(0, |acc, count| {
+                    acc | (0..4).fold(!0, |eq, i| {
+                        if count >> i & 1 == 1 {
+                            eq & digits[i]
+                        } else {
+                            eq & !digits[i]
+                        }
+                    })
+                });
+                result.words[y as usize * self.words_per_row + w] = at_least;
+            }
+        }
+
+        result.clear_padding();
+        result
+    }
+
+    // Moves every cell of the row one column right, so each x sees its left neighbour
+    fn shifted_left(row: &[u64], w: usize) -> u64 {
+        let carry = if w > 0 { row[w - 1] >> 63 } else { 0 };
+        row[w] << 1 | carry
+    }
+
+    // Moves every cell of the row one column left, so each x sees its right neighbour
+    fn shifted_right(row: &[u64], w: usize) -> u64 {
+        let carry = if w + 1 < row.len() {
+            row[w + 1] << 63
+        } else {
+            0
+        };
+        row[w] >> 1 | carry
+    }
+
+    fn row(&self, y: i64) -> &[u64] {
+        assert!(y >= 0 && y < self.height, "Row {} is out of bounds", y);
+        let start = y as usize * self.words_per_row;
+        &self.words[start..start + self.words_per_row]
+    }
+
+    fn locate(&self, point: Point) -> Option<(usize, u32)> {
+        if point.x < 0 || point.x >= self.width || point.y < 0 || point.y >= self.height {
+            return None;
+        }
+        let word = point.y as usize * self.words_per_row + point.x as usize / 64;
+        Some((word, (point.x % 64) as u32))
+    }
+
+    fn clear_padding(&mut self) {
+        let used = (self.width % 64) as u32;
+        if used == 0 {
+            return;
+        }
+        let mask = (1u64 << used) - 1;
+        for row in self.words.chunks_mut(self.words_per_row) {
+            *row.last_mut().unwrap() &= mask;
+        }
+    }
+
+    fn combine(&self, other: &BitGrid, op: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert_eq!(self.size(), other.size(), "Grids must have the same size");
+        BitGrid {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |a, b| a ^ b)
+    }
+}
+
+impl BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, other: &BitGrid) {
+        assert_eq!(self.size(), other.size(), "Grids must have the same size");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a ^= b;
+        }
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut result = BitGrid {
+            words: self.words.iter().map(|w| !w).collect(),
+            ..self.clone()
+        };
+        result.clear_padding();
+        result
+    }
+}
+
+impl GridLike for BitGrid {
+    type Cell = bool;
+
+    fn bounds(&self) -> Option<Rect> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        Some(Rect::new(
+            Point::new(0, 0),
+            Point::new(self.width - 1, self.height - 1),
+        ))
+    }
+
+    fn get(&self, point: Point) -> Option<bool> {
+        BitGrid::get(self, point)
+    }
+
+    fn set(&mut self, point: Point, value: bool) {
+        BitGrid::set(self, point, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn random(size: Point, seed: u64) -> BitGrid {
+        let mut rng = Rng::new(seed);
+        let mut grid = BitGrid::new(size);
+        for p in size.iter_inside() {
+            grid.set(p, rng.chance(0.5));
+        }
+        grid
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = BitGrid::new(Point::new(130, 2));
+        grid.set(Point::new(0, 0), true);
+        grid.set(Point::new(64, 0), true);
+        grid.set(Point::new(129, 1), true);
+        grid.set(Point::new(130, 1), true);
+
+        assert_eq!(grid.get(Point::new(64, 0)), Some(true));
+        assert_eq!(grid.get(Point::new(63, 0)), Some(false));
+        assert_eq!(grid.get(Point::new(130, 1)), None);
+        assert_eq!(grid.count_ones(), 3);
+        assert_eq!(grid.row_count_ones(0), 2);
+
+        grid.set(Point::new(64, 0), false);
+        assert_eq!(grid.count_ones(), 2);
+    }
+
+    #[test]
+    fn test_bitwise_ops() {
+        let a = random(Point::new(70, 3), 1);
+        let b = random(Point::new(70, 3), 2);
+
+        for p in a.size().iter_inside() {
+            let (x, y) = (a.get(p).unwrap(), b.get(p).unwrap());
+            assert_eq!((&a & &b).get(p), Some(x & y));
+            assert_eq!((&a | &b).get(p), Some(x | y));
+            assert_eq!((&a ^ &b).get(p), Some(x ^ y));
+            assert_eq!((!&a).get(p), Some(!x));
+        }
+
+        assert_eq!((!&a).count_ones(), 70 * 3 - a.count_ones());
+        let mut c = a.clone();
+        c ^= &a;
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn test_neighbours_at_least() {
+        for (size, seed) in [(Point::new(10, 10), 3), (Point::new(129, 5), 4)] {
+            let grid = random(size, seed);
+            for k in 0..=9 {
+                let fast = grid.neighbours_at_least(k);
+                for p in size.iter_inside() {
+                    let count = p
+                        .neighbours()
+                        .filter(|&n| grid.get(n) == Some(true))
+                        .count() as u32;
+                    assert_eq!(fast.get(p), Some(count >= k), "{:?} k={}", p, k);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use super::{Grid, ToChar};
+use crate::point::Point;
+
+const HIGHLIGHT: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+impl<T: PartialEq> Grid<T> {
+    // Cells that differ between the two grids as (point, old, new), row by row
+    pub fn diff<'a>(&'a self, other: &'a Grid<T>) -> impl Iterator<Item = (Point, &'a T, &'a T)> {
+        assert_eq!(
+            self.size(),
+            other.size(),
+            "Only grids of the same size can be compared"
+        );
+        self.iter()
+            .zip(other.cells.iter())
+            .filter(|((_, old), new)| old != new)
+            .map(|((point, old), new)| (point, old, new))
+    }
+}
+
+impl<T: PartialEq + ToChar> Grid<T> {
+    // The old grid on the left, the new one on the right and a '*' under every
+    // change in between
+    pub fn render_side_by_side(&self, other: &Grid<T>) -> String {
+        let changed: HashSet<Point> = self.diff(other).map(|(point, _, _)| point).collect();
+        let old = self.to_string();
+        let new = other.to_string();
+        let marks = self.render_with(|point, _| if changed.contains(&point) { '*' } else { ' ' });
+
+        old.lines()
+            .zip(marks.lines())
+            .zip(new.lines())
+            .map(|((old, marks), new)| format!("{} {} {}", old, marks, new))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    // Highlighted with `colour`, side by side otherwise
+    pub fn render_changes(&self, other: &Grid<T>, colour: bool) -> String {
+        if colour {
+            self.render_highlighted(other)
+        } else {
+            self.render_side_by_side(other)
+        }
+    }
+
+    // The new grid with changed cells in reverse video, for terminals
+    pub fn render_highlighted(&self, other: &Grid<T>) -> String {
+        let changed: HashSet<Point> = self.diff(other).map(|(point, _, _)| point).collect();
+        let mut out = String::new();
+        for (point, cell) in other.iter() {
+            if point.x == 0 && point.y > 0 {
+                out.push('\n');
+            }
+            if changed.contains(&point) {
+                out.push_str(HIGHLIGHT);
+                out.push(cell.to_char());
+                out.push_str(RESET);
+            } else {
+                out.push(cell.to_char());
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Digit(u8);
+
+    impl ToChar for Digit {
+        fn to_char(&self) -> char {
+            (b'0' + self.0) as char
+        }
+    }
+
+    fn grids() -> (Grid<Digit>, Grid<Digit>) {
+        let old = Grid::new(vec![
+            vec![Digit(1), Digit(2), Digit(3)],
+            vec![Digit(4), Digit(5), Digit(6)],
+        ]);
+        let mut new = old.clone();
+        new[Point::new(1, 0)] = Digit(0);
+        new[Point::new(2, 1)] = Digit(9);
+        (old, new)
+    }
+
+    #[test]
+    fn test_diff() {
+        let (old, new) = grids();
+        let changes: Vec<_> = old.diff(&new).collect();
+
+        assert_eq!(
+            changes,
+            vec![
+                (Point::new(1, 0), &Digit(2), &Digit(0)),
+                (Point::new(2, 1), &Digit(6), &Digit(9))
+            ]
+        );
+        assert_eq!(old.diff(&old).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_diff_different_sizes() {
+        let (old, _) = grids();
+        let _ = old.diff(&Grid::filled(Point::new(2, 2), Digit(0))).count();
+    }
+
+    #[test]
+    fn test_render() {
+        let (old, new) = grids();
+
+        assert_eq!(old.render_side_by_side(&new), "123  *  103\n456   * 459");
+        assert_eq!(
+            old.render_highlighted(&new),
+            "1\x1b[7m0\x1b[0m3\n45\x1b[7m9\x1b[0m"
+        );
+        assert_eq!(
+            old.render_changes(&new, false),
+            old.render_side_by_side(&new)
+        );
+        assert_eq!(old.render_changes(&new, true), old.render_highlighted(&new));
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use super::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+// Image exporters. Every cell becomes a `scale` x `scale` square of pixels.
+impl<T> Grid<T> {
+    // Binary PPM (P6)
+    pub fn write_ppm(
+        &self,
+        out: &mut impl Write,
+        scale: usize,
+        colour: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        self.write_netpbm(out, "P6", scale, |cell| {
+            let Rgb(r, g, b) = colour(cell);
+            vec![r, g, b]
+        })
+    }
+
+    // Binary greyscale PGM (P5), 0 is black
+    pub fn write_pgm(
+        &self,
+        out: &mut impl Write,
+        scale: usize,
+        shade: impl Fn(&T) -> u8,
+    ) -> io::Result<()> {
+        self.write_netpbm(out, "P5", scale, |cell| vec![shade(cell)])
+    }
+
+    pub fn to_svg(&self, scale: usize, colour: impl Fn(&T) -> Rgb) -> String {
+        assert!(scale > 0, "Scale must be at least 1");
+        let size = self.size();
+        let (width, height) = (size.x as usize * scale, size.y as usize * scale);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+            width, height, width, height
+        );
+        for (point, cell) in self.iter() {
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                point.x as usize * scale,
+                point.y as usize * scale,
+                scale,
+                scale,
+                colour(cell).hex()
+            )
+            .unwrap();
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn write_netpbm(
+        &self,
+        out: &mut impl Write,
+        magic: &str,
+        scale: usize,
+        pixel: impl Fn(&T) -> Vec<u8>,
+    ) -> io::Result<()> {
+        assert!(scale > 0, "Scale must be at least 1");
+        let size = self.size();
+        write!(
+            out,
+            "{}\n{} {}\n255\n",
+            magic,
+            size.x as usize * scale,
+            size.y as usize * scale
+        )?;
+
+        for row in self.rows() {
+            let mut line = Vec::new();
+            for cell in row {
+                let pixel = pixel(cell);
+                for _ in 0..scale {
+                    line.extend_from_slice(&pixel);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Numbered images of a simulation: frame-00000.ppm, frame-00001.ppm, ...
+pub struct Frames {
+    dir: PathBuf,
+    count: usize,
+}
+
+impl Frames {
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        fs::create_dir_all(dir.as_ref())?;
+        Ok(Frames {
+            dir: dir.as_ref().to_path_buf(),
+            count: 0,
+        })
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn save_ppm<T>(
+        &mut self,
+        grid: &Grid<T>,
+        scale: usize,
+        colour: impl Fn(&T) -> Rgb,
+    ) -> io::Result<PathBuf> {
+        let path = self.next_path("ppm");
+        let mut out = io::BufWriter::new(fs::File::create(&path)?);
+        grid.write_ppm(&mut out, scale, colour)?;
+        out.flush()?;
+        Ok(path)
+    }
+
+    pub fn save_pgm<T>(
+        &mut self,
+        grid: &Grid<T>,
+        scale: usize,
+        shade: impl Fn(&T) -> u8,
+    ) -> io::Result<PathBuf> {
+        let path = self.next_path("pgm");
+        let mut out = io::BufWriter::new(fs::File::create(&path)?);
+        grid.write_pgm(&mut out, scale, shade)?;
+        out.flush()?;
+        Ok(path)
+    }
+
+    pub fn save_svg<T>(
+        &mut self,
+        grid: &Grid<T>,
+        scale: usize,
+        colour: impl Fn(&T) -> Rgb,
+    ) -> io::Result<PathBuf> {
+        let path = self.next_path("svg");
+        fs::write(&path, grid.to_svg(scale, colour))?;
+        Ok(path)
+    }
+
+    fn next_path(&mut self, extension: &str) -> PathBuf {
+        let path = self
+            .dir
+            .join(format!("frame-{:05}.{}", self.count, extension));
+        self.count += 1;
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<bool> {
+        Grid::new(vec![vec![true, false], vec![false, false]])
+    }
+
+    fn colour(&cell: &bool) -> Rgb {
+        if cell { Rgb(255, 0, 0) } else { Rgb(0, 0, 0) }
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut out = Vec::new();
+        grid().write_ppm(&mut out, 1, colour).unwrap();
+
+        let header = b"P6\n2 2\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        assert_eq!(
+            &out[header.len()..],
+            &[255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_pgm_scaled() {
+        let mut out = Vec::new();
+        grid()
+            .write_pgm(&mut out, 2, |&cell| if cell { 200 } else { 10 })
+            .unwrap();
+
+        let header = b"P5\n4 4\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        let pixels = &out[header.len()..];
+        assert_eq!(pixels.len(), 16);
+        assert_eq!(&pixels[..8], &[200, 200, 10, 10, 200, 200, 10, 10]);
+        assert!(pixels[8..].iter().all(|&p| p == 10));
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = grid().to_svg(10, colour);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"20\" height=\"20\""));
+        assert_eq!(svg.matches("<rect").count(), 4);
+        assert!(
+            svg.contains("<rect x=\"0\" y=\"0\" width=\"10\" height=\"10\" fill=\"#ff0000\"/>")
+        );
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"10\" width=\"10\" height=\"10\" fill=\"#000000\"/>")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale must be at least 1")]
+    fn test_svg_zero_scale() {
+        grid().to_svg(0, colour);
+    }
+
+    #[test]
+    fn test_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir).unwrap();
+
+        let first = frames.save_ppm(&grid(), 1, colour).unwrap();
+        let second = frames.save_svg(&grid(), 1, colour).unwrap();
+
+        assert_eq!(first.file_name().unwrap(), "frame-00000.ppm");
+        assert_eq!(second.file_name().unwrap(), "frame-00001.svg");
+        assert_eq!(frames.count(), 2);
+        assert!(fs::read(&first).unwrap().starts_with(b"P6"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,179 @@
+use super::regions::{self, Components};
+use super::{Grid, SparseGrid};
+use crate::point::{Neighbourhood, Point};
+use crate::rect::Rect;
+
+// What the grid algorithms (search, flood fill, ...) need from a grid, so they
+// work the same on every representation
+pub trait GridLike {
+    type Cell: Copy;
+
+    // The area algorithms work in, None when the grid has no cells
+    fn bounds(&self) -> Option<Rect>;
+
+    // None for points normalise() rejects
+    fn get(&self, point: Point) -> Option<Self::Cell>;
+
+    fn set(&mut self, point: Point, value: Self::Cell);
+
+    fn in_bounds(&self, point: Point) -> bool {
+        self.bounds().is_some_and(|bounds| bounds.contains(point))
+    }
+
+    // The point inside bounds() addressing the same cell, for grids that wrap around
+    fn normalise(&self, point: Point) -> Option<Point> {
+        self.in_bounds(point).then_some(point)
+    }
+
+    // Fewest orthogonal steps between two cells, ignoring what's in them
+    fn manhattan(&self, a: Point, b: Point) -> u64 {
+        a.manhattan(&b)
+    }
+
+    // Every point inside bounds() with its cell, row by row
+    fn cells(&self) -> impl Iterator<Item = (Point, Self::Cell)> + '_ {
+        self.bounds()
+            .into_iter()
+            .flat_map(|bounds| bounds.points())
+            .filter_map(|p| self.get(p).map(|cell| (p, cell)))
+    }
+
+    fn neighbours_in<'a>(
+        &'a self,
+        point: Point,
+        neighbourhood: &Neighbourhood<'a>,
+    ) -> impl Iterator<Item = (Point, Self::Cell)> + 'a {
+        point
+            .neighbours_in(neighbourhood)
+            .filter_map(move |p| self.get(p).map(|cell| (p, cell)))
+    }
+
+    // All points orthogonally connected to `start` through cells accepted by
+    // `predicate`, empty when start itself isn't accepted
+    fn flood_fill(&self, start: Point, predicate: impl Fn(Point, Self::Cell) -> bool) -> Vec<Point>
+    where
+        Self: Sized,
+    {
+        regions::flood_fill(self, start, predicate)
+    }
+
+    // Groups the cells accepted by `predicate` into regions connected through
+    // `connectivity`, labelled in row-major order of their first cell
+    fn components(
+        &self,
+        predicate: impl Fn(Point, Self::Cell) -> bool,
+        connectivity: &Neighbourhood,
+    ) -> Components
+    where
+        Self: Sized,
+    {
+        regions::components(self, predicate, connectivity)
+    }
+}
+
+impl<T: Copy> GridLike for Grid<T> {
+    type Cell = T;
+
+    fn bounds(&self) -> Option<Rect> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        Some(Rect::new(
+            Point::new(0, 0),
+            Point::new(self.width - 1, self.height - 1),
+        ))
+    }
+
+    fn get(&self, point: Point) -> Option<T> {
+        self.value(point)
+    }
+
+    fn set(&mut self, point: Point, value: T) {
+        self.replace(point, value);
+    }
+
+    fn normalise(&self, point: Point) -> Option<Point> {
+        Grid::normalise(self, point)
+    }
+
+    // On a torus the way around the seam can be shorter
+    fn manhattan(&self, a: Point, b: Point) -> u64 {
+        match (self.wrapping, self.normalise(a), self.normalise(b)) {
+            (true, Some(a), Some(b)) => {
+                let size = self.size();
+                let (dx, dy) = (a.x.abs_diff(b.x), a.y.abs_diff(b.y));
+                dx.min(size.x as u64 - dx) + dy.min(size.y as u64 - dy)
+            }
+            _ => a.manhattan(&b),
+        }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (Point, T)> + '_ {
+        self.values()
+    }
+
+    fn neighbours_in<'a>(
+        &'a self,
+        point: Point,
+        neighbourhood: &Neighbourhood<'a>,
+    ) -> impl Iterator<Item = (Point, T)> + 'a {
+        Grid::neighbours_in(self, point, neighbourhood).map(|(p, &cell)| (p, cell))
+    }
+}
+
+// A sparse grid is bounded by the cells stored in it, everything inside reads
+// as stored or default
+impl<T: Copy> GridLike for SparseGrid<T> {
+    type Cell = T;
+
+    fn bounds(&self) -> Option<Rect> {
+        SparseGrid::bounds(self)
+    }
+
+    fn get(&self, point: Point) -> Option<T> {
+        self.in_bounds(point).then(|| SparseGrid::get(self, point))
+    }
+
+    fn set(&mut self, point: Point, value: T) {
+        self.replace(point, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_set<G: GridLike<Cell = bool>>(grid: &G) -> usize {
+        grid.cells().filter(|(_, cell)| *cell).count()
+    }
+
+    #[test]
+    fn test_dense_and_sparse_agree() {
+        let dense = Grid::new(vec![
+            vec![false, true, false],
+            vec![false, false, false],
+            vec![true, false, true],
+        ]);
+        let mut sparse = SparseGrid::from_grid(&dense, false);
+
+        assert_eq!(count_set(&dense), 3);
+        assert_eq!(count_set(&sparse), 3);
+        assert_eq!(GridLike::bounds(&dense), GridLike::bounds(&sparse));
+
+        let centre = Point::new(1, 1);
+        for neighbourhood in [Neighbourhood::Moore, Neighbourhood::VonNeumann] {
+            let mut dense_neighbours: Vec<_> =
+                GridLike::neighbours_in(&dense, centre, &neighbourhood).collect();
+            let mut sparse_neighbours: Vec<_> =
+                GridLike::neighbours_in(&sparse, centre, &neighbourhood).collect();
+            dense_neighbours.sort_by_key(|(p, _)| (p.y, p.x));
+            sparse_neighbours.sort_by_key(|(p, _)| (p.y, p.x));
+            assert_eq!(dense_neighbours, sparse_neighbours);
+        }
+
+        assert_eq!(GridLike::get(&sparse, Point::new(3, 0)), None);
+        GridLike::set(&mut sparse, Point::new(3, 0), true);
+        assert_eq!(GridLike::get(&sparse, Point::new(3, 0)), Some(true));
+        assert_eq!(GridLike::get(&sparse, Point::new(3, 1)), Some(false));
+    }
+}
@@ -0,0 +1,503 @@
+mod bits;
+mod diff;
+mod image;
+mod like;
+mod parser;
+mod regions;
+mod sparse;
+#[cfg(test)]
+pub(crate) mod testing;
+mod transform;
+
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::point::{Neighbourhood, Point};
+use crate::tasks::task::TaskInput;
+
+pub use bits::BitGrid;
+pub use image::{Frames, Rgb};
+pub use like::GridLike;
+pub use parser::{GridParser, ParseError};
+pub use regions::{Components, Region};
+pub use sparse::SparseGrid;
+
+// Cells are stored row by row in a single Vec: (x, y) lives at y * width + x.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: i64,
+    height: i64,
+    cells: Vec<T>,
+    // coordinates are taken modulo the size, the grid is a torus
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn new(values: Vec<Vec<T>>) -> Self {
+        assert!(!values.is_empty(), "Grid cannot be empty");
+        let width = values[0].len() as i64;
+        for row in &values {
+            assert!(
+                row.len() as i64 == width,
+                "All rows in the grid must have the same length"
+            );
+        }
+
+        let height = values.len() as i64;
+
+        Grid {
+            width,
+            height,
+            cells: values.into_iter().flatten().collect(),
+            wrapping: false,
+        }
+    }
+
+    // A grid of the given size where every cell is computed from its position
+    pub fn from_fn(size: Point, f: impl FnMut(Point) -> T) -> Self {
+        assert!(size.x >= 0 && size.y >= 0, "Grid size cannot be negative");
+        Grid {
+            width: size.x,
+            height: size.y,
+            cells: size.iter_inside().map(f).collect(),
+            wrapping: false,
+        }
+    }
+
+    // Makes every point address a cell: get, neighbours and walks leaving one
+    // side come back on the opposite one
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        self.wrapping
+    }
+
+    // The in-bounds point addressing the same cell as `point`, None if there's no such cell
+    pub fn normalise(&self, point: Point) -> Option<Point> {
+        let size = self.size();
+        if self.wrapping && !self.cells.is_empty() {
+            Some(point.wrap(&size))
+        } else {
+            point.is_inside(&size).then_some(point)
+        }
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.normalise(point)
+            .map(|p| (p.y * self.width + p.x) as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point {
+            x: index as i64 % self.width,
+            y: index as i64 / self.width,
+        }
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    pub fn replace(&mut self, point: Point, value: T) {
+        if let Some(cell) = self.get_mut(point) {
+            *cell = value;
+        }
+    }
+
+    pub fn size(&self) -> Point {
+        Point {
+            x: self.width,
+            y: self.height,
+        }
+    }
+
+    pub fn row(&self, y: i64) -> &[T] {
+        assert!(y >= 0 && y < self.height, "Row {} is out of bounds", y);
+        let start = (y * self.width) as usize;
+        &self.cells[start..start + self.width as usize]
+    }
+
+    pub fn row_mut(&mut self, y: i64) -> &mut [T] {
+        assert!(y >= 0 && y < self.height, "Row {} is out of bounds", y);
+        let start = (y * self.width) as usize;
+        &mut self.cells[start..start + self.width as usize]
+    }
+
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> {
+        assert!(x >= 0 && x < self.width, "Column {} is out of bounds", x);
+        self.cells
+            .iter()
+            .skip(x as usize)
+            .step_by(self.width as usize)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // In-bounds Moore neighbours of `point` together with their values
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours_in(point, &Neighbourhood::Moore)
+    }
+
+    // In-bounds von Neumann neighbours of `point` together with their values
+    pub fn direct_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours_in(point, &Neighbourhood::VonNeumann)
+    }
+
+    pub fn neighbours_in<'a>(
+        &'a self,
+        point: Point,
+        neighbourhood: &Neighbourhood<'a>,
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        point
+            .neighbours_in(neighbourhood)
+            .filter_map(move |p| self.normalise(p))
+            .map(move |p| (p, &self[p]))
+    }
+
+    // The cells at start, start + step, start + 2 * step... until the walk leaves
+    // the grid. On a wrapping grid it never does.
+    pub fn walk(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        assert!(step != Point::new(0, 0), "Walk step cannot be zero");
+        std::iter::successors(self.normalise(start), move |&p| self.normalise(p + step))
+            .map(move |p| (p, &self[p]))
+    }
+
+    pub fn iter(&self) -> GridIterator<'_, T> {
+        GridIterator {
+            grid: self,
+            index: 0,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.cells.iter_mut().enumerate().map(move |(i, cell)| {
+            let point = Point {
+                x: i as i64 % width,
+                y: i as i64 / width,
+            };
+            (point, cell)
+        })
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(point, cell)| f(point, cell)).collect(),
+            wrapping: self.wrapping,
+        }
+    }
+
+    // Points of the cells accepted by `predicate`, row by row
+    pub fn positions(&self, predicate: impl Fn(&T) -> bool) -> impl Iterator<Item = Point> {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.cells.iter().filter(|cell| predicate(cell)).count()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // A grid of the given size with every cell set to `value`
+    pub fn filled(size: Point, value: T) -> Self {
+        assert!(size.x >= 0 && size.y >= 0, "Grid size cannot be negative");
+        Grid {
+            width: size.x,
+            height: size.y,
+            cells: vec![value; (size.x * size.y) as usize],
+            wrapping: false,
+        }
+    }
+}
+
+// Small Copy cells are usually easier to work with by value
+impl<T: Copy> Grid<T> {
+    pub fn value(&self, point: Point) -> Option<T> {
+        self.get(point).copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = (Point, T)> {
+        self.iter().map(|(point, &cell)| (point, cell))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.index_of(point) {
+            Some(i) => &self.cells[i],
+            None => panic!("Point {:?} is out of the grid bounds", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.index_of(point) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("Point {:?} is out of the grid bounds", point),
+        }
+    }
+}
+
+pub struct GridIterator<'a, T> {
+    grid: &'a Grid<T>,
+    index: usize,
+}
+
+impl<'a, T> Iterator for GridIterator<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.grid.cells.get(self.index)?;
+        let point = self.grid.point_of(self.index);
+
+        self.index += 1;
+        Some((point, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.grid.cells.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+// The inverse of a cell's TaskInput::from_str, used to print grids back in the
+// puzzle's format
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl<T: ToChar> Grid<T> {
+    // Renders the grid with every point in `points` drawn as `mark`
+    pub fn render_overlay(&self, points: impl IntoIterator<Item = Point>, mark: char) -> String {
+        let points: HashSet<Point> = points
+            .into_iter()
+            .filter_map(|point| self.normalise(point))
+            .collect();
+        self.render_with(|point, cell| {
+            if points.contains(&point) {
+                mark
+            } else {
+                cell.to_char()
+            }
+        })
+    }
+
+    fn render_with(&self, to_char: impl Fn(Point, &T) -> char) -> String {
+        let mut out = String::with_capacity(self.cells.len() + self.height as usize);
+        for (point, cell) in self.iter() {
+            if point.x == 0 && point.y > 0 {
+                out.push('\n');
+            }
+            out.push(to_char(point, cell));
+        }
+        out
+    }
+}
+
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(|_, cell| cell.to_char()))
+    }
+}
+
+impl<T: TaskInput + Clone> TaskInput for Grid<T> {
+    fn from_str(s: &str) -> Self {
+        GridParser::new(|c| Ok::<_, Infallible>(T::from_str(&c.to_string())))
+            .parse(s)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::Cell;
+    use super::*;
+
+    #[test]
+    fn test_iterator() {
+        let grid = Grid::new(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9],
+            vec![10, 11, 12],
+        ]);
+
+        assert_eq!(grid.iter().count(), 12);
+        assert_eq!(grid.iter().nth(3).unwrap(), (Point { x: 0, y: 1 }, &4));
+        assert_eq!(grid.iter().nth(7).unwrap(), (Point { x: 1, y: 2 }, &8));
+    }
+
+    #[test]
+    fn test_index() {
+        let mut grid = Grid::filled(Point::new(3, 2), 0);
+        grid[Point::new(2, 1)] = 5;
+        *grid.get_mut(Point::new(0, 1)).unwrap() += 3;
+
+        assert_eq!(grid[Point::new(2, 1)], 5);
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&3));
+        assert_eq!(grid.value(Point::new(0, 1)), Some(3));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert!(grid.get_mut(Point::new(-1, 0)).is_none());
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).wrapping();
+
+        assert!(grid.is_wrapping());
+        assert_eq!(grid.get(Point::new(3, 0)), Some(&1));
+        assert_eq!(grid.get(Point::new(-1, -1)), Some(&6));
+        assert_eq!(grid[Point::new(7, 5)], 5);
+        assert_eq!(grid.normalise(Point::new(-4, 2)), Some(Point::new(2, 0)));
+
+        // every cell has 8 neighbours on a torus, corners included
+        let neighbours: Vec<_> = grid.neighbours(Point::new(0, 0)).collect();
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.contains(&(Point::new(2, 1), &6)));
+        assert!(neighbours.iter().all(|&(p, _)| p.is_inside(&grid.size())));
+
+        let mapped = grid.map(|_, &cell| cell * 10);
+        assert_eq!(mapped.get(Point::new(-1, 0)), Some(&30));
+    }
+
+    #[test]
+    fn test_walk() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let bounded: Vec<i32> = grid
+            .walk(Point::new(0, 0), Point::new(1, 1))
+            .map(|(_, &cell)| cell)
+            .collect();
+        assert_eq!(bounded, vec![1, 5]);
+        assert_eq!(grid.walk(Point::new(3, 0), Point::new(1, 0)).count(), 0);
+
+        let torus = grid.wrapping();
+        let wrapped: Vec<i32> = torus
+            .walk(Point::new(0, 0), Point::new(1, 1))
+            .take(6)
+            .map(|(_, &cell)| cell)
+            .collect();
+        assert_eq!(wrapped, vec![1, 5, 3, 4, 2, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Walk step cannot be zero")]
+    fn test_walk_zero_step() {
+        let torus = Grid::filled(Point::new(3, 2), 0).wrapping();
+        let _ = torus.walk(Point::new(0, 0), Point::new(0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::filled(Point::new(3, 2), 0);
+        let _ = grid[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let corner: Vec<(Point, &i32)> = grid.neighbours(Point::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![
+                (Point::new(1, 0), &2),
+                (Point::new(0, 1), &4),
+                (Point::new(1, 1), &5)
+            ]
+        );
+
+        assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 5);
+        assert_eq!(
+            grid.direct_neighbours(Point::new(1, 1))
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>(),
+            vec![2, 4, 6]
+        );
+        assert_eq!(grid.neighbours(Point::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours_in() {
+        let grid = Grid::filled(Point::new(5, 5), 1);
+        let centre = Point::new(2, 2);
+
+        assert_eq!(
+            grid.neighbours_in(centre, &Neighbourhood::Radius(2))
+                .count(),
+            24
+        );
+        assert_eq!(
+            grid.neighbours_in(Point::new(0, 0), &Neighbourhood::Radius(2))
+                .count(),
+            8
+        );
+
+        let knight = [Point::new(1, 2), Point::new(-1, 2), Point::new(2, -1)];
+        let moves: Vec<Point> = grid
+            .neighbours_in(Point::new(0, 0), &Neighbourhood::Custom(&knight))
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(moves, vec![Point::new(1, 2)]);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let input = "..#.\n#...\n.##.";
+        let grid: Grid<Cell> = Grid::from_str(input);
+
+        assert_eq!(grid.to_string(), input);
+        assert_eq!(Grid::<Cell>::from_str(&grid.to_string()), grid);
+    }
+
+    #[test]
+    fn test_render_overlay() {
+        let grid: Grid<Cell> = Grid::from_str("...\n.#.");
+
+        assert_eq!(
+            grid.render_overlay([Point::new(0, 0), Point::new(2, 1), Point::new(5, 5)], 'o'),
+            "o..\n.#o"
+        );
+
+        let torus = grid.wrapping();
+        assert_eq!(
+            torus.render_overlay([Point::new(-1, 0), Point::new(3, 3)], 'o'),
+            "..o\no#."
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.columns().map(|c| c.sum::<i32>()).collect::<Vec<_>>(),
+            vec![5, 7, 9]
+        );
+    }
+}
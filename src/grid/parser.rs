@@ -0,0 +1,187 @@
+use std::fmt;
+
+use super::Grid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError<E> {
+    Empty,
+    // rows and columns are counted from 0
+    Cell {
+        row: usize,
+        column: usize,
+        error: E,
+    },
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "Grid cannot be empty"),
+            ParseError::Cell { row, column, error } => {
+                write!(
+                    f,
+                    "Invalid cell at row {}, column {}: {}",
+                    row, column, error
+                )
+            }
+            ParseError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} cells, expected {} like the rows before it",
+                row, found, expected
+            ),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ParseError<E> {}
+
+// Builds a Grid from text with a per character parser. By default every line is
+// trimmed and all lines must have the same length.
+pub struct GridParser<T, F> {
+    parse: F,
+    padding: Option<T>,
+    keep_whitespace: bool,
+}
+
+impl<T: Clone, E, F: Fn(char) -> Result<T, E>> GridParser<T, F> {
+    pub fn new(parse: F) -> Self {
+        GridParser {
+            parse,
+            padding: None,
+            keep_whitespace: false,
+        }
+    }
+
+    // Fills rows shorter than the longest one with `value` instead of failing
+    pub fn pad_with(mut self, value: T) -> Self {
+        self.padding = Some(value);
+        self
+    }
+
+    // Leading and trailing whitespace of every line is passed to the parser too
+    pub fn keep_whitespace(mut self) -> Self {
+        self.keep_whitespace = true;
+        self
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Grid<T>, ParseError<E>> {
+        let mut rows = Vec::new();
+        for (row, line) in input.lines().enumerate() {
+            let line = if self.keep_whitespace {
+                line
+            } else {
+                line.trim()
+            };
+            let cells = line
+                .chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    (self.parse)(c).map_err(|error| ParseError::Cell { row, column, error })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+            rows.push(cells);
+        }
+
+        if rows.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        match &self.padding {
+            Some(value) => {
+                let width = rows.iter().map(Vec::len).max().unwrap();
+                for row in rows.iter_mut() {
+                    row.resize(width, value.clone());
+                }
+            }
+            None => {
+                let expected = rows[0].len();
+                if let Some(row) = rows.iter().position(|r| r.len() != expected) {
+                    return Err(ParseError::RaggedRow {
+                        row,
+                        expected,
+                        found: rows[row].len(),
+                    });
+                }
+            }
+        }
+
+        Ok(Grid::new(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+
+    fn digit(c: char) -> Result<u32, String> {
+        c.to_digit(10)
+            .ok_or_else(|| format!("'{}' is not a digit", c))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = GridParser::new(digit).parse(" 123\n456 \n").unwrap();
+
+        assert_eq!(grid, Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+    }
+
+    #[test]
+    fn test_errors() {
+        let parser = GridParser::new(digit);
+
+        assert_eq!(parser.parse(""), Err(ParseError::Empty));
+        assert_eq!(
+            parser.parse("123\n4x6"),
+            Err(ParseError::Cell {
+                row: 1,
+                column: 1,
+                error: "'x' is not a digit".to_string()
+            })
+        );
+
+        let ragged = parser.parse("123\n456\n78");
+        assert_eq!(
+            ragged,
+            Err(ParseError::RaggedRow {
+                row: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            ragged.unwrap_err().to_string(),
+            "Row 2 has 2 cells, expected 3 like the rows before it"
+        );
+    }
+
+    #[test]
+    fn test_padding_and_whitespace() {
+        // a worksheet where the editor stripped trailing spaces
+        let worksheet = "12 3\n 4\n*  +";
+        let grid = GridParser::new(Ok::<char, String>)
+            .keep_whitespace()
+            .pad_with(' ')
+            .parse(worksheet)
+            .unwrap();
+
+        assert_eq!(grid.size(), Point::new(4, 3));
+        assert_eq!(grid.row(1), &[' ', '4', ' ', ' ']);
+        assert_eq!(grid[Point::new(3, 2)], '+');
+
+        let trimmed = GridParser::new(Ok::<char, String>)
+            .pad_with('.')
+            .parse(worksheet)
+            .unwrap();
+        assert_eq!(trimmed.row(1), &['4', '.', '.', '.']);
+    }
+}
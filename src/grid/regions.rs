@@ -0,0 +1,215 @@
+use std::collections::{HashMap, HashSet};
+
+use super::GridLike;
+use crate::point::{Neighbourhood, Point};
+use crate::rect::Rect;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub points: Vec<Point>,
+    pub size: usize,
+    // number of cell sides that don't touch another cell of the region
+    pub perimeter: usize,
+    // in grid coordinates, so a region crossing the seam of a torus spans the whole side
+    pub bounds: Rect,
+}
+
+#[derive(Debug, Clone)]
+pub struct Components {
+    // index into regions for every cell that belongs to one
+    pub labels: HashMap<Point, usize>,
+    pub regions: Vec<Region>,
+}
+
+pub(super) fn flood_fill<G: GridLike>(
+    grid: &G,
+    start: Point,
+    predicate: impl Fn(Point, G::Cell) -> bool,
+) -> Vec<Point> {
+    let mut visited = HashSet::new();
+    fill_from(
+        grid,
+        start,
+        &predicate,
+        &Neighbourhood::VonNeumann,
+        &mut visited,
+    )
+}
+
+pub(super) fn components<G: GridLike>(
+    grid: &G,
+    predicate: impl Fn(Point, G::Cell) -> bool,
+    connectivity: &Neighbourhood,
+) -> Components {
+    let mut visited = HashSet::new();
+    let mut labels = HashMap::new();
+    let mut regions = Vec::new();
+
+    for (point, _) in grid.cells() {
+        if visited.contains(&point) {
+            continue;
+        }
+        let points = fill_from(grid, point, &predicate, connectivity, &mut visited);
+        if points.is_empty() {
+            continue;
+        }
+        for &p in &points {
+            labels.insert(p, regions.len());
+        }
+        regions.push(points);
+    }
+
+    let regions = regions
+        .into_iter()
+        .enumerate()
+        .map(|(label, points)| {
+            let perimeter = points
+                .iter()
+                .flat_map(|p| p.direct_neighbours())
+                .filter(|&n| grid.normalise(n).and_then(|n| labels.get(&n)) != Some(&label))
+                .count();
+            let bounds = points
+                .iter()
+                .fold(Rect::new(points[0], points[0]), |r, &p| r.including(p));
+            Region {
+                size: points.len(),
+                points,
+                perimeter,
+                bounds,
+            }
+        })
+        .collect();
+
+    Components { labels, regions }
+}
+
+fn fill_from<G: GridLike>(
+    grid: &G,
+    start: Point,
+    predicate: &impl Fn(Point, G::Cell) -> bool,
+    connectivity: &Neighbourhood,
+    visited: &mut HashSet<Point>,
+) -> Vec<Point> {
+    let Some(start) = grid.normalise(start) else {
+        return Vec::new();
+    };
+    match grid.get(start) {
+        Some(cell) if !visited.contains(&start) && predicate(start, cell) => {}
+        _ => return Vec::new(),
+    }
+
+    visited.insert(start);
+    let mut points = Vec::new();
+    let mut stack = vec![start];
+    while let Some(point) = stack.pop() {
+        points.push(point);
+        for (next, cell) in grid.neighbours_in(point, connectivity) {
+            if !visited.contains(&next) && predicate(next, cell) {
+                visited.insert(next);
+                stack.push(next);
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::SparseGrid;
+    use crate::grid::testing::walls;
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = walls("##..\n#..#\n..##");
+        let filled = grid.flood_fill(Point::new(2, 0), |_, wall| !wall);
+
+        assert_eq!(filled.len(), 6);
+        assert!(filled.contains(&Point::new(0, 2)));
+        assert!(!filled.contains(&Point::new(0, 0)));
+
+        assert!(
+            grid.flood_fill(Point::new(0, 0), |_, wall| !wall)
+                .is_empty()
+        );
+        assert!(
+            grid.flood_fill(Point::new(9, 0), |_, wall| !wall)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = walls("##..\n#.#.\n...#");
+
+        let orthogonal = grid.components(|_, wall| wall, &Neighbourhood::VonNeumann);
+        assert_eq!(orthogonal.regions.len(), 3);
+
+        let corner = &orthogonal.regions[0];
+        assert_eq!(corner.size, 3);
+        assert_eq!(corner.perimeter, 8);
+        assert_eq!(corner.bounds, Rect::new(Point::new(0, 0), Point::new(1, 1)));
+        assert_eq!(orthogonal.labels.get(&Point::new(0, 1)), Some(&0));
+        assert_eq!(orthogonal.labels.get(&Point::new(2, 1)), Some(&1));
+        assert_eq!(orthogonal.labels.get(&Point::new(3, 2)), Some(&2));
+        assert_eq!(orthogonal.labels.get(&Point::new(2, 0)), None);
+
+        let diagonal = grid.components(|_, wall| wall, &Neighbourhood::Moore);
+        assert_eq!(diagonal.regions.len(), 1);
+        assert_eq!(diagonal.regions[0].size, 5);
+        assert_eq!(diagonal.regions[0].perimeter, 16);
+        assert_eq!(
+            diagonal.regions[0].bounds,
+            Rect::new(Point::new(0, 0), Point::new(3, 2))
+        );
+    }
+
+    #[test]
+    fn test_flood_fill_on_torus() {
+        let torus = walls("...\n.#.").wrapping();
+        let mut filled = torus.flood_fill(Point::new(-1, 0), |_, wall| !wall);
+        filled.sort_by_key(|p| (p.y, p.x));
+
+        assert_eq!(
+            filled,
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(0, 1),
+                Point::new(2, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_components_across_the_seam() {
+        let torus = walls("#..#\n....\n....").wrapping();
+
+        let components = torus.components(|_, wall| wall, &Neighbourhood::VonNeumann);
+        assert_eq!(components.regions.len(), 1);
+        let region = &components.regions[0];
+        assert_eq!(region.size, 2);
+        assert_eq!(region.perimeter, 6);
+        assert_eq!(region.bounds, Rect::new(Point::new(0, 0), Point::new(3, 0)));
+
+        // a ring around the torus has no ends
+        let ring = walls("####\n....\n....").wrapping();
+        let components = ring.components(|_, wall| wall, &Neighbourhood::VonNeumann);
+        assert_eq!(components.regions[0].perimeter, 8);
+    }
+
+    #[test]
+    fn test_sparse_components() {
+        let mut walls = SparseGrid::new(false);
+        for p in [Point::new(-10, 0), Point::new(-9, 0), Point::new(40, 7)] {
+            walls.replace(p, true);
+        }
+
+        let components = walls.components(|_, wall| wall, &Neighbourhood::VonNeumann);
+        assert_eq!(components.regions.len(), 2);
+        assert_eq!(components.regions[0].size, 2);
+        assert_eq!(components.regions[0].perimeter, 6);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use super::Grid;
+use crate::point::Point;
+use crate::rect::Rect;
+
+// An unbounded grid that only stores the cells that were set. Every other cell
+// has the default value.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T: Copy> {
+    default: T,
+    cells: HashMap<Point, T>,
+    // kept up to date on every insert so bounds() is cheap
+    bounds: Option<Rect>,
+}
+
+impl<T: Copy> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            default,
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    // Keeps only the cells of `grid` that differ from `default`
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+    where
+        T: PartialEq,
+    {
+        let cells = grid.values().filter(|(_, cell)| *cell != default).collect();
+        Self::with_cells(default, cells)
+    }
+
+    pub fn default_value(&self) -> T {
+        self.default
+    }
+
+    pub fn get(&self, point: Point) -> T {
+        self.cells.get(&point).copied().unwrap_or(self.default)
+    }
+
+    // Only stored cells can be changed in place, see entry for the others
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    // The stored cell, inserting the default value first if there's none
+    pub fn entry(&mut self, point: Point) -> &mut T {
+        if !self.cells.contains_key(&point) {
+            self.grow(point);
+        }
+        self.cells.entry(point).or_insert(self.default)
+    }
+
+    pub fn replace(&mut self, point: Point, value: T) {
+        self.grow(point);
+        self.cells.insert(point, value);
+    }
+
+    // Resets the cell back to the default value
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        let bounds = self.bounds.unwrap();
+        // only a cell on the edge of the bounds can shrink them
+        if point.x == bounds.min.x
+            || point.x == bounds.max.x
+            || point.y == bounds.min.y
+            || point.y == bounds.max.y
+        {
+            self.bounds = Self::bounds_of(&self.cells);
+        }
+        Some(removed)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    // Number of stored cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // The smallest rectangle containing every stored cell, None when nothing is stored
+    pub fn bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    // The stored cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, T)> + '_ {
+        self.cells.iter().map(|(&point, &cell)| (point, cell))
+    }
+
+    // A dense copy of the cells inside `rect`, its top left corner becomes (0, 0)
+    pub fn to_grid(&self, rect: Rect) -> Grid<T> {
+        Grid::from_fn(rect.size(), |p| self.get(p + rect.min))
+    }
+
+    fn with_cells(default: T, cells: HashMap<Point, T>) -> Self {
+        SparseGrid {
+            default,
+            bounds: Self::bounds_of(&cells),
+            cells,
+        }
+    }
+
+    fn grow(&mut self, point: Point) {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(point),
+            None => Rect::new(point, point),
+        });
+    }
+
+    fn bounds_of(cells: &HashMap<Point, T>) -> Option<Rect> {
+        let mut points = cells.keys();
+        let first = *points.next()?;
+        Some(points.fold(Rect::new(first, first), |r, &p| r.including(p)))
+    }
+}
+
+impl<T: Copy> FromIterator<(Point, T)> for SparseGrid<T>
+where
+    T: Default,
+{
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        Self::with_cells(T::default(), iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_and_replace() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.get(Point::new(-50_000, 50_000)), '.');
+        assert_eq!(grid.bounds(), None);
+
+        grid.replace(Point::new(49_000, 1_700), '#');
+        grid.replace(Point::new(-3, 2), '#');
+        *grid.entry(Point::new(0, 0)) = 'S';
+
+        assert_eq!(grid.get(Point::new(49_000, 1_700)), '#');
+        assert_eq!(grid.get(Point::new(0, 0)), 'S');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(
+            grid.bounds(),
+            Some(Rect::new(Point::new(-3, 0), Point::new(49_000, 1_700)))
+        );
+
+        assert_eq!(grid.remove(Point::new(49_000, 1_700)), Some('#'));
+        assert_eq!(grid.get(Point::new(49_000, 1_700)), '.');
+        assert_eq!(
+            grid.bounds(),
+            Some(Rect::new(Point::new(-3, 0), Point::new(0, 2)))
+        );
+    }
+
+    #[test]
+    fn test_get_mut_and_entry() {
+        let mut grid = SparseGrid::new(0);
+        grid.replace(Point::new(1, 1), 5);
+
+        // looking at a missing cell doesn't store it
+        assert_eq!(grid.get_mut(Point::new(100, 100)), None);
+        assert_eq!(grid.len(), 1);
+        assert_eq!(
+            grid.bounds(),
+            Some(Rect::new(Point::new(1, 1), Point::new(1, 1)))
+        );
+
+        *grid.get_mut(Point::new(1, 1)).unwrap() += 1;
+        assert_eq!(grid.get(Point::new(1, 1)), 6);
+
+        *grid.entry(Point::new(1, 1)) += 1;
+        *grid.entry(Point::new(-2, 3)) += 4;
+        assert_eq!(grid.get(Point::new(1, 1)), 7);
+        assert_eq!(grid.get(Point::new(-2, 3)), 4);
+        assert_eq!(
+            grid.bounds(),
+            Some(Rect::new(Point::new(-2, 1), Point::new(1, 3)))
+        );
+    }
+
+    #[test]
+    fn test_dense_conversion() {
+        let dense = Grid::new(vec![vec![0, 1, 0], vec![0, 0, 2]]);
+        let sparse = SparseGrid::from_grid(&dense, 0);
+
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(Point::new(2, 1)), 2);
+        assert_eq!(
+            sparse.to_grid(Rect::new(Point::new(0, 0), Point::new(2, 1))),
+            dense
+        );
+        assert_eq!(
+            sparse.to_grid(sparse.bounds().unwrap()),
+            Grid::new(vec![vec![1, 0], vec![0, 2]])
+        );
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let sparse: SparseGrid<u32> = [(Point::new(5, 5), 1), (Point::new(-5, 5), 2)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(sparse.get(Point::new(-5, 5)), 2);
+        assert_eq!(sparse.get(Point::new(0, 5)), 0);
+        assert_eq!(sparse.iter().map(|(_, v)| v).sum::<u32>(), 3);
+    }
+}
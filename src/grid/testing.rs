@@ -0,0 +1,37 @@
+// Fixtures shared by the grid, search and automaton tests
+use std::convert::Infallible;
+
+use super::{Grid, GridParser, ToChar};
+use crate::tasks::task::TaskInput;
+
+// '#' is true, anything else false
+pub(crate) fn walls(s: &str) -> Grid<bool> {
+    GridParser::new(|c| Ok::<bool, Infallible>(c == '#'))
+        .parse(s)
+        .unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Cell {
+    Open,
+    Wall,
+}
+
+impl TaskInput for Cell {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "." => Cell::Open,
+            "#" => Cell::Wall,
+            _ => panic!("Unexpected cell: {}", s),
+        }
+    }
+}
+
+impl ToChar for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Open => '.',
+            Cell::Wall => '#',
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use super::Grid;
+use crate::point::Point;
+use crate::rect::Rect;
+
+impl<T: Clone> Grid<T> {
+    // Like from_fn, but a transformed torus stays a torus
+    fn transformed(&self, size: Point, f: impl FnMut(Point) -> T) -> Self {
+        Grid {
+            wrapping: self.wrapping,
+            ..Grid::from_fn(size, f)
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.transformed(Point::new(self.height, self.width), |p| {
+            self[Point::new(p.y, height - 1 - p.x)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        self.transformed(Point::new(self.height, self.width), |p| {
+            self[Point::new(width - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    // Rotates clockwise by `quarter_turns` * 90°, negative values turn counterclockwise
+    pub fn rotate(&self, quarter_turns: i64) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => self.clone(),
+            1 => self.rotate_clockwise(),
+            2 => self.flip_horizontal().flip_vertical(),
+            _ => self.rotate_counterclockwise(),
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.transformed(Point::new(self.height, self.width), |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    // Mirrors left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        self.transformed(self.size(), |p| {
+            self[Point::new(width - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    // Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        self.transformed(self.size(), |p| {
+            self[Point::new(p.x, height - 1 - p.y)].clone()
+        })
+    }
+
+    pub fn subgrid(&self, rect: Rect) -> Self {
+        assert!(
+            rect.min.is_inside(&self.size()) && rect.max.is_inside(&self.size()),
+            "Rect {:?} is out of the grid bounds",
+            rect
+        );
+        self.transformed(rect.size(), |p| self[p + rect.min].clone())
+    }
+
+    // Surrounds the grid with `amount` cells of `value` on every side
+    pub fn pad(&self, amount: i64, value: T) -> Self {
+        assert!(amount >= 0, "Padding cannot be negative");
+        let size = Point::new(self.width + 2 * amount, self.height + 2 * amount);
+        self.transformed(size, |p| {
+            let p = Point::new(p.x - amount, p.y - amount);
+            if p.is_inside(&self.size()) {
+                self[p].clone()
+            } else {
+                value.clone()
+            }
+        })
+    }
+
+    // Repeats the grid `times.x` times horizontally and `times.y` times vertically
+    pub fn tile(&self, times: Point) -> Self {
+        assert!(
+            times.x >= 0 && times.y >= 0,
+            "Tile count cannot be negative"
+        );
+        let size = Point::new(self.width * times.x, self.height * times.y);
+        self.transformed(size, |p| {
+            self[Point::new(p.x % self.width, p.y % self.height)].clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<i32> {
+        Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_rotate() {
+        let clockwise = grid().rotate_clockwise();
+        assert_eq!(clockwise.size(), Point::new(2, 3));
+        assert_eq!(
+            clockwise,
+            Grid::new(vec![vec![4, 1], vec![5, 2], vec![6, 3]])
+        );
+
+        let counterclockwise = grid().rotate_counterclockwise();
+        assert_eq!(
+            counterclockwise,
+            Grid::new(vec![vec![3, 6], vec![2, 5], vec![1, 4]])
+        );
+
+        assert_eq!(
+            grid().rotate(2),
+            Grid::new(vec![vec![6, 5, 4], vec![3, 2, 1]])
+        );
+        assert_eq!(grid().rotate(-1), counterclockwise);
+        assert_eq!(grid().rotate(5), clockwise);
+        assert_eq!(grid().rotate(4), grid());
+    }
+
+    #[test]
+    fn test_transpose_and_flip() {
+        assert_eq!(
+            grid().transpose(),
+            Grid::new(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+        assert_eq!(
+            grid().flip_horizontal(),
+            Grid::new(vec![vec![3, 2, 1], vec![6, 5, 4]])
+        );
+        assert_eq!(
+            grid().flip_vertical(),
+            Grid::new(vec![vec![4, 5, 6], vec![1, 2, 3]])
+        );
+        assert_eq!(grid().transpose().transpose(), grid());
+    }
+
+    #[test]
+    fn test_subgrid() {
+        let sub = grid().subgrid(Rect::new(Point::new(1, 0), Point::new(2, 1)));
+        assert_eq!(sub, Grid::new(vec![vec![2, 3], vec![5, 6]]));
+
+        let single = grid().subgrid(Rect::new(Point::new(0, 1), Point::new(0, 1)));
+        assert_eq!(single, Grid::new(vec![vec![4]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_subgrid_out_of_bounds() {
+        grid().subgrid(Rect::new(Point::new(1, 0), Point::new(3, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the grid bounds")]
+    fn test_subgrid_out_of_bounds_on_torus() {
+        // every point is a cell of a torus, but the rect still has to fit in it
+        grid()
+            .wrapping()
+            .subgrid(Rect::new(Point::new(-1, 0), Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_transforms_keep_wrapping() {
+        let torus = grid().wrapping();
+        for transformed in [
+            torus.rotate_clockwise(),
+            torus.rotate_counterclockwise(),
+            torus.rotate(2),
+            torus.transpose(),
+            torus.flip_horizontal(),
+            torus.flip_vertical(),
+            torus.subgrid(Rect::new(Point::new(0, 0), Point::new(1, 1))),
+            torus.pad(1, 0),
+            torus.tile(Point::new(2, 1)),
+        ] {
+            assert!(transformed.is_wrapping());
+        }
+        assert!(!grid().rotate_clockwise().is_wrapping());
+
+        let rotated = torus.rotate_clockwise();
+        assert_eq!(rotated.get(Point::new(-1, 0)), Some(&1));
+    }
+
+    #[test]
+    fn test_pad_and_tile() {
+        let padded = grid().pad(1, 0);
+        assert_eq!(padded.size(), Point::new(5, 4));
+        assert_eq!(padded.row(0), &[0, 0, 0, 0, 0]);
+        assert_eq!(padded.row(2), &[0, 4, 5, 6, 0]);
+        assert_eq!(grid().pad(0, 0), grid());
+
+        let tiled = grid().tile(Point::new(2, 2));
+        assert_eq!(tiled.size(), Point::new(6, 4));
+        assert_eq!(tiled.row(1), &[4, 5, 6, 4, 5, 6]);
+        assert_eq!(tiled.row(2), &[1, 2, 3, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,16 @@
+pub mod automaton;
+pub mod config;
+pub mod direction;
+pub mod grid;
+pub mod point;
+pub mod rect;
+pub mod rng;
+pub mod search;
+pub mod tasks;
+
+pub use direction::{Direction, Heading};
+pub use grid::{BitGrid, Frames, Grid, GridLike, GridParser, Rgb, SparseGrid, ToChar};
+pub use point::{Line, Neighbourhood, Point, Point2, Point3, PointN};
+pub use rect::Rect;
+pub use tasks::task::{InputGenerator, Runnable, Task, TaskInput};
+pub use tasks::{Registration, find, registry};
@@ -1,74 +1,232 @@
-use clap::Parser;
-mod grid;
-mod point;
-mod tasks;
-use tasks::task::Task;
+use aoc2025::Frames;
+use aoc2025::config::{CONFIG_FILE, Config};
+use aoc2025::tasks;
+use aoc2025::tasks::fuzz;
+use aoc2025::tasks::task::DEFAULT_SOLVER;
+use clap::{Parser, Subcommand};
+use std::io::IsTerminal;
+
+const DEFAULT_YEAR: u32 = tasks::y2025::YEAR;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    day: u32,
-    part: u32,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(required = true)]
+    day: Option<u32>,
+    #[arg(required = true)]
+    part: Option<u32>,
+    /// Defaults to `year` in aoc.conf, then to the latest season
+    #[arg(long, env = "AOC_YEAR", global = true)]
+    year: Option<u32>,
+    #[arg(long, default_value = DEFAULT_SOLVER)]
+    solver: String,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print a random valid input for the day
+    Generate {
+        day: u32,
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Compare all solvers of the day on generated inputs
+    Crosscheck {
+        day: u32,
+        #[arg(long, default_value_t = 20)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[arg(long, default_value_t = 100)]
+        runs: u64,
+    },
+    /// Feed mutated inputs to a parser, or to all of them if no target is given
+    Fuzz {
+        /// `<year>-day<day>` or `grid`
+        target: Option<String>,
+        #[arg(long, default_value_t = 10000)]
+        iterations: u64,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Directory to write crashing inputs to
+        #[arg(long)]
+        save: Option<std::path::PathBuf>,
+    },
+    /// Write numbered frames of the day's solution on the real input
+    Visualise {
+        day: u32,
+        /// Directory to write the frames to
+        #[arg(long, default_value = "frames")]
+        out: std::path::PathBuf,
+    },
+    /// Print the steps of the day's solution on the real input
+    Trace { day: u32 },
 }
 
 fn main() {
     let args = Args::parse();
+    let year = args
+        .year
+        .or_else(|| Config::load(std::path::Path::new(CONFIG_FILE)).year)
+        .unwrap_or(DEFAULT_YEAR);
 
-    let input = std::fs::read_to_string(format!("inputs/day{}", args.day))
-        .expect("Failed to read input file");
+    match args.command {
+        Some(Command::Generate { day, size, seed }) => generate(year, day, size, seed),
+        Some(Command::Crosscheck {
+            day,
+            size,
+            seed,
+            runs,
+        }) => crosscheck(year, day, size, seed, runs),
+        Some(Command::Fuzz {
+            target,
+            iterations,
+            seed,
+            save,
+        }) => fuzz(target, iterations, seed, save),
+        Some(Command::Visualise { day, out }) => visualise(year, day, out),
+        Some(Command::Trace { day }) => trace(year, day),
+        None => run(year, args.day.unwrap(), args.part.unwrap(), &args.solver),
+    }
+}
 
-    match (args.day, args.part) {
-        (1, part) => {
-            let task = tasks::day1::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
-        }
-        (2, part) => {
-            let task = tasks::day2::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
-        }
-        (3, part) => {
-            let task = tasks::day3::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
-        }
-        (4, part) => {
-            let task = tasks::day4::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
+fn run(year: u32, day: u32, part: u32, solver: &str) {
+    let registration = match tasks::find(year, day) {
+        Some(registration) => registration,
+        None => {
+            println!("Task not implemented");
+            return;
         }
-        (5, part) => {
-            let task = tasks::day5::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
+    };
+
+    let solvers = registration.task.solver_names(part);
+    if !solvers.contains(&solver) {
+        println!(
+            "Unknown solver {}, available: {}",
+            solver,
+            solvers.join(", ")
+        );
+        return;
+    }
+
+    let input = std::fs::read_to_string(format!("inputs/{}/day{}", year, day))
+        .expect("Failed to read input file");
+
+    let result = registration.task.run_solver(&input, part, solver).unwrap();
+    println!("Result: {}", result);
+}
+
+fn generate(year: u32, day: u32, size: usize, seed: u64) {
+    let generator = match tasks::find(year, day).and_then(|r| r.task.generator()) {
+        Some(generator) => generator,
+        None => {
+            println!("Generator not implemented");
+            return;
         }
-        (6, part) => {
-            let task = tasks::day6::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
+    };
+
+    println!("{}", generator.generate(size, seed));
+}
+
+fn crosscheck(year: u32, day: u32, size: usize, seed: u64, runs: u64) {
+    let registration = match tasks::find(year, day) {
+        Some(registration) if registration.task.generator().is_some() => registration,
+        _ => {
+            println!("Generator not implemented");
+            return;
         }
-        (7, part) => {
-            let task = tasks::day7::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
+    };
+
+    match tasks::crosscheck::crosscheck(registration, size, seed, runs) {
+        None => println!("All solvers agree on {} inputs", runs),
+        Some(disagreement) => {
+            println!("Solvers disagree on part {}:", disagreement.part);
+            for (name, result) in disagreement.results {
+                match result {
+                    Ok(answer) => println!("  {}: {}", name, answer),
+                    Err(message) => println!("  {}: panicked: {}", name, message),
+                }
+            }
+            println!("Minimized input:\n{}", disagreement.input);
         }
-        (8, part) => {
-            let task = tasks::day8::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
+    }
+}
+
+fn fuzz(target: Option<String>, iterations: u64, seed: u64, save: Option<std::path::PathBuf>) {
+    let targets = match target {
+        Some(name) => match fuzz::find_target(&name) {
+            Some(target) => vec![target],
+            None => {
+                let names: Vec<String> = fuzz::targets().into_iter().map(|t| t.name).collect();
+                println!("Unknown target {}, available: {}", name, names.join(", "));
+                return;
+            }
+        },
+        None => fuzz::targets(),
+    };
+
+    for target in targets {
+        let crashes = fuzz::fuzz(&target, iterations, seed);
+        println!("{}: {} distinct crashes", target.name, crashes.len());
+
+        for (i, crash) in crashes.iter().enumerate() {
+            println!("  {}", crash.panic);
+            println!("    input: {:?}", crash.input);
+
+            if let Some(dir) = &save {
+                let dir = dir.join(&target.name);
+                std::fs::create_dir_all(&dir).expect("Failed to create crash directory");
+                std::fs::write(dir.join(format!("crash-{}", i)), &crash.input)
+                    .expect("Failed to write crash input");
+            }
         }
-        (9, part) => {
-            let task = tasks::day9::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
+    }
+}
+
+fn visualise(year: u32, day: u32, out: std::path::PathBuf) {
+    let registration = match tasks::find(year, day) {
+        Some(registration) => registration,
+        None => {
+            println!("Task not implemented");
+            return;
         }
-        (10, part) => {
-            let task = tasks::day10::Task {};
-            let result = task.run(&input, part);
-            println!("Result: {}", result);
+    };
+
+    let input = std::fs::read_to_string(format!("inputs/{}/day{}", year, day))
+        .expect("Failed to read input file");
+    let mut frames = Frames::new(&out).expect("Failed to create frame directory");
+
+    match registration.task.visualise_str(&input, &mut frames) {
+        None => println!("Visualisation not implemented"),
+        Some(Err(error)) => println!("Failed to write frames: {}", error),
+        Some(Ok(())) => println!("Wrote {} frames to {}", frames.count(), out.display()),
+    }
+}
+
+fn trace(year: u32, day: u32) {
+    let registration = match tasks::find(year, day) {
+        Some(registration) => registration,
+        None => {
+            println!("Task not implemented");
+            return;
         }
+    };
+
+    let input = std::fs::read_to_string(format!("inputs/{}/day{}", year, day))
+        .expect("Failed to read input file");
 
-        _ => println!("Task not implemented"),
+    // no escape codes when the trace goes to a file or a pipe
+    let colour = std::io::stdout().is_terminal();
+    let mut out = std::io::stdout().lock();
+    match registration.task.trace_str(&input, &mut out, colour) {
+        None => println!("Trace not implemented"),
+        Some(Err(error)) => println!("Failed to write trace: {}", error),
+        Some(Ok(())) => {}
     }
 }
@@ -1,29 +1,66 @@
-use std::{array::IntoIter, ops::Add};
+use std::borrow::Cow;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
+use crate::direction::Direction;
+
+// in row-major order
 const NEIGHBOURS: [Point; 8] = [
-    Point { x: -1, y: -1 },
-    Point { x: 0, y: -1 },
-    Point { x: 1, y: -1 },
-    Point { x: -1, y: 0 },
-    Point { x: 1, y: 0 },
-    Point { x: -1, y: 1 },
-    Point { x: 0, y: 1 },
-    Point { x: 1, y: 1 },
+    Direction::NorthWest.offset(),
+    Direction::North.offset(),
+    Direction::NorthEast.offset(),
+    Direction::West.offset(),
+    Direction::East.offset(),
+    Direction::SouthWest.offset(),
+    Direction::South.offset(),
+    Direction::SouthEast.offset(),
 ];
 
 const ORTHOGONAL_NEIGHBOURS: [Point; 4] = [
-    Point { x: 0, y: -1 },
-    Point { x: -1, y: 0 },
-    Point { x: 1, y: 0 },
-    Point { x: 0, y: 1 },
+    Direction::North.offset(),
+    Direction::West.offset(),
+    Direction::East.offset(),
+    Direction::South.offset(),
 ];
 
+// Which offsets around a point count as its neighbours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood<'a> {
+    // the 4 orthogonal neighbours
+    VonNeumann,
+    // all 8 surrounding points
+    Moore,
+    // every point within r steps in each axis (Chebyshev distance), without the centre
+    Radius(i64),
+    Custom(&'a [Point]),
+}
+
+impl<'a> Neighbourhood<'a> {
+    pub fn offsets(&self) -> Cow<'a, [Point]> {
+        match *self {
+            Neighbourhood::VonNeumann => Cow::Borrowed(&ORTHOGONAL_NEIGHBOURS),
+            Neighbourhood::Moore => Cow::Borrowed(&NEIGHBOURS),
+            Neighbourhood::Radius(r) => Cow::Owned(
+                Point::new(2 * r + 1, 2 * r + 1)
+                    .iter_inside()
+                    .map(|p| p - Point::new(r, r))
+                    .filter(|&p| p != Point::new(0, 0))
+                    .collect(),
+            ),
+            Neighbourhood::Custom(offsets) => Cow::Borrowed(offsets),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point {
     pub x: i64,
     pub y: i64,
 }
 
+// The operators overflow like the i64 coordinates do: a panic in debug builds and
+// wrapping around in release builds. Division truncates toward zero and panics
+// when dividing by zero.
 impl Add for Point {
     type Output = Point;
 
@@ -35,6 +72,74 @@ impl Add for Point {
     }
 }
 
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+impl Div<i64> for Point {
+    type Output = Point;
+
+    fn div(self, divisor: i64) -> Point {
+        Point {
+            x: self.x / divisor,
+            y: self.y / divisor,
+        }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Sum for Point {
+    fn sum<I: Iterator<Item = Point>>(iter: I) -> Point {
+        iter.fold(Point::new(0, 0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Point> for Point {
+    fn sum<I: Iterator<Item = &'a Point>>(iter: I) -> Point {
+        iter.copied().sum()
+    }
+}
+
 impl Point {
     pub fn new(x: i64, y: i64) -> Self {
         Point { x, y }
@@ -48,39 +153,275 @@ impl Point {
         NEIGHBOURS.iter().map(|offset| *self + *offset)
     }
 
+    pub fn neighbours_in<'a>(
+        &self,
+        neighbourhood: &Neighbourhood<'a>,
+    ) -> impl Iterator<Item = Point> + use<'a> {
+        let point = *self;
+        let offsets = neighbourhood.offsets();
+        (0..offsets.len()).map(move |i| point + offsets[i])
+    }
+
     // iter_inside accepts a point and iterates over all points from (0,0) to (x-1,y-1)
     pub fn iter_inside(&self) -> impl Iterator<Item = Point> {
-        (0..self.y).flat_map(move |y| {
-            (0..self.x).map(move |x| Point {
-                x: x as i64,
-                y: y as i64,
+        (0..self.y).flat_map(move |y| (0..self.x).map(move |x| Point { x, y }))
+    }
+
+    // The distance along one axis always fits in a u64, the sum panics when it doesn't
+    pub fn manhattan(&self, other: &Point) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .checked_add(self.y.abs_diff(other.y))
+            .expect("Distance overflows u64")
+    }
+
+    // Exact for any two points
+    pub fn chebyshev(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    // Panics once an axis differs by 2^32 or more, the square no longer fits in a u64
+    pub fn distance_squared(&self, other: &Point) -> u64 {
+        squared_distance([self.x.abs_diff(other.x), self.y.abs_diff(other.y)])
+    }
+
+    // Each coordinate reduced to -1, 0 or 1: the direction of a straight or
+    // diagonal move towards this point
+    pub fn signum(&self) -> Point {
+        Point {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    // Rotations by 90° about the origin, as seen on screen with y growing downwards
+    pub fn rotate_clockwise(&self) -> Point {
+        Point {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Point {
+        Point {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    pub fn is_inside(&self, size: &Point) -> bool {
+        (0..size.x).contains(&self.x) && (0..size.y).contains(&self.y)
+    }
+
+    // The point inside `size` equal to this one modulo the size, as on a torus
+    pub fn wrap(&self, size: &Point) -> Point {
+        Point {
+            x: self.x.rem_euclid(size.x),
+            y: self.y.rem_euclid(size.y),
+        }
+    }
+
+    // Every point from self to other, both included
+    pub fn line(&self, other: &Point) -> Line {
+        Line::new(*self, *other)
+    }
+}
+
+// The points of a straight line, computed on the fly with Bresenham's algorithm.
+// Horizontal, vertical and 45° lines are exact. Other slopes give the closest
+// points, which can differ from the line walked in the opposite direction.
+// Any two points work, the deltas are kept in i128 so they can't overflow.
+#[derive(Debug, Clone)]
+pub struct Line {
+    current: Point,
+    step: Point,
+    // |dx| and -|dy|
+    dx: i128,
+    dy: i128,
+    error: i128,
+    // up to 2^64, from i64::MIN to i64::MAX
+    remaining: u128,
+}
+
+impl Line {
+    pub fn new(start: Point, end: Point) -> Self {
+        let dx = start.x.abs_diff(end.x) as i128;
+        let dy = -(start.y.abs_diff(end.y) as i128);
+        Line {
+            current: start,
+            step: Point::new(end.x.cmp(&start.x) as i64, end.y.cmp(&start.y) as i64),
+            dx,
+            dy,
+            error: dx + dy,
+            remaining: start.chebyshev(&end) as u128 + 1,
+        }
+    }
+
+    // Leaves out the first point, e.g. to chain lines without repeating corners
+    pub fn without_start(mut self) -> Self {
+        self.next();
+        self
+    }
+
+    pub fn without_end(mut self) -> Self {
+        self.remaining = self.remaining.saturating_sub(1);
+        self
+    }
+
+    // Number of points left, exact even for a line over the whole i64 range
+    pub fn remaining(&self) -> u128 {
+        self.remaining
+    }
+}
+
+impl Iterator for Line {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let point = self.current;
+        if self.remaining > 0 {
+            let doubled = 2 * self.error;
+            if doubled >= self.dy {
+                self.error += self.dy;
+                self.current.x += self.step.x;
+            }
+            if doubled <= self.dx {
+                self.error += self.dx;
+                self.current.y += self.step.y;
+            }
+        }
+        Some(point)
+    }
+
+    // A line over the whole i64 range has one point more than usize::MAX fits
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+// A point with any number of integer coordinates, for puzzles outside of the plane.
+// The 2D Point above stays the workhorse of the grids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointN<const D: usize> {
+    pub coords: [i64; D],
+}
+
+pub type Point2 = PointN<2>;
+pub type Point3 = PointN<3>;
+
+impl<const D: usize> PointN<D> {
+    pub fn new(coords: [i64; D]) -> Self {
+        PointN { coords }
+    }
+
+    pub fn origin() -> Self {
+        PointN { coords: [0; D] }
+    }
+
+    // All 3^D - 1 points that differ by at most 1 in every coordinate
+    pub fn neighbours(&self) -> impl Iterator<Item = PointN<D>> + use<D> {
+        let point = *self;
+        (0..3usize.pow(D as u32))
+            .map(move |mut digits| {
+                let mut neighbour = point;
+                for coord in neighbour.coords.iter_mut() {
+                    *coord += (digits % 3) as i64 - 1;
+                    digits /= 3;
+                }
+                neighbour
+            })
+            .filter(move |&neighbour| neighbour != point)
+    }
+
+    // The 2 * D points one step away along a single axis
+    pub fn direct_neighbours(&self) -> impl Iterator<Item = PointN<D>> + use<D> {
+        let point = *self;
+        (0..D).flat_map(move |axis| {
+            [-1, 1].map(|step| {
+                let mut neighbour = point;
+                neighbour.coords[axis] += step;
+                neighbour
             })
         })
     }
 
-    pub fn line(&self, other: &Point) -> impl Iterator<Item = Point> {
-        let dx = other.x - self.x;
-        let dy = other.y - self.y;
-
-        assert!(
-            dx * dy == 0,
-            "Only horizontal or vertical lines are supported"
-        );
-
-        // there's probably a better way to do this with iterators and types magic
-        // It's too late now
-        if dx == 0 {
-            (self.y.min(other.y)..=self.y.max(other.y))
-                .into_iter()
-                .map(|y| Point { x: self.x, y })
-                .collect::<Vec<Point>>()
-                .into_iter()
-        } else {
-            (self.x.min(other.x)..=self.x.max(other.x))
-                .into_iter()
-                .map(|x| Point { x, y: self.y })
-                .collect::<Vec<Point>>()
-                .into_iter()
+    // Same limits as Point::distance_squared
+    pub fn distance_squared(&self, other: &PointN<D>) -> u64 {
+        squared_distance(
+            self.coords
+                .iter()
+                .zip(other.coords.iter())
+                .map(|(a, b)| a.abs_diff(*b)),
+        )
+    }
+}
+
+fn squared_distance(axes: impl IntoIterator<Item = u64>) -> u64 {
+    axes.into_iter()
+        .try_fold(0u64, |sum, d| d.checked_pow(2)?.checked_add(sum))
+        .expect("Distance overflows u64")
+}
+
+impl Point2 {
+    pub fn x(&self) -> i64 {
+        self.coords[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.coords[1]
+    }
+}
+
+impl Point3 {
+    pub fn x(&self) -> i64 {
+        self.coords[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.coords[1]
+    }
+
+    pub fn z(&self) -> i64 {
+        self.coords[2]
+    }
+}
+
+impl<const D: usize> Add for PointN<D> {
+    type Output = PointN<D>;
+
+    fn add(self, other: PointN<D>) -> PointN<D> {
+        PointN {
+            coords: std::array::from_fn(|i| self.coords[i] + other.coords[i]),
         }
     }
 }
+
+impl<const D: usize> Sub for PointN<D> {
+    type Output = PointN<D>;
+
+    fn sub(self, other: PointN<D>) -> PointN<D> {
+        PointN {
+            coords: std::array::from_fn(|i| self.coords[i] - other.coords[i]),
+        }
+    }
+}
+
+impl From<Point> for Point2 {
+    fn from(point: Point) -> Self {
+        PointN::new([point.x, point.y])
+    }
+}
+
+impl From<Point2> for Point {
+    fn from(point: Point2) -> Self {
+        Point::new(point.x(), point.y())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::point::Point;
+
+// An axis aligned rectangle, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    pub fn new(a: Point, b: Point) -> Self {
+        Rect {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn size(&self) -> Point {
+        Point::new(self.max.x - self.min.x + 1, self.max.y - self.min.y + 1)
+    }
+
+    pub fn area(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    // Every point of the rectangle, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+
+    // The smallest rectangle containing both self and `point`
+    pub fn including(&self, point: Point) -> Self {
+        Rect {
+            min: Point::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            max: Point::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::new(Point::new(4, 1), Point::new(2, 3));
+
+        assert_eq!(rect.min, Point::new(2, 1));
+        assert_eq!(rect.max, Point::new(4, 3));
+        assert_eq!(rect.size(), Point::new(3, 3));
+        assert_eq!(rect.area(), 9);
+        assert!(rect.contains(Point::new(4, 3)));
+        assert!(!rect.contains(Point::new(5, 3)));
+        assert_eq!(rect.points().count(), 9);
+        assert_eq!(rect.points().nth(1), Some(Point::new(3, 1)));
+
+        let grown = rect.including(Point::new(0, 5));
+        assert_eq!(grown, Rect::new(Point::new(0, 1), Point::new(4, 5)));
+    }
+}
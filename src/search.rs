@@ -0,0 +1,268 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::grid::GridLike;
+use crate::point::{Neighbourhood, Point};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub cost: u64,
+    // from start to goal, both included
+    pub points: Vec<Point>,
+}
+
+// Number of steps from `start` to every reachable cell, moving orthogonally
+// through cells accepted by `passable`. Unreachable cells are missing.
+pub fn bfs<G: GridLike>(
+    grid: &G,
+    start: Point,
+    passable: impl Fn(Point, G::Cell) -> bool,
+) -> HashMap<Point, u64> {
+    let mut distances = HashMap::new();
+    let Some(start) = grid.normalise(start) else {
+        return distances;
+    };
+
+    distances.insert(start, 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(point) = queue.pop_front() {
+        let distance = distances[&point];
+        for (next, cell) in grid.neighbours_in(point, &Neighbourhood::VonNeumann) {
+            if !distances.contains_key(&next) && passable(next, cell) {
+                distances.insert(next, distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+// Cheapest orthogonal path from `start` to `goal`. `cost(from, to)` is the price
+// of a single move, None if the move isn't allowed.
+pub fn dijkstra<G: GridLike>(
+    grid: &G,
+    start: Point,
+    goal: Point,
+    cost: impl Fn(Point, Point) -> Option<u64>,
+) -> Option<Path> {
+    search(grid, start, goal, cost, |_, _| 0)
+}
+
+// Same as dijkstra, guided by the Manhattan distance to the goal, taken around
+// the seams on a wrapping grid. The result is only guaranteed to be optimal when
+// every move costs at least 1.
+pub fn astar<G: GridLike>(
+    grid: &G,
+    start: Point,
+    goal: Point,
+    cost: impl Fn(Point, Point) -> Option<u64>,
+) -> Option<Path> {
+    search(grid, start, goal, cost, |p, goal| grid.manhattan(p, goal))
+}
+
+fn search<G: GridLike>(
+    grid: &G,
+    start: Point,
+    goal: Point,
+    cost: impl Fn(Point, Point) -> Option<u64>,
+    heuristic: impl Fn(Point, Point) -> u64,
+) -> Option<Path> {
+    // on a torus many points name the same cell, the search only sees normalised ones
+    let start = grid.normalise(start)?;
+    let goal = grid.normalise(goal)?;
+
+    let mut best = HashMap::from([(start, 0)]);
+    let mut came_from = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(start, goal), 0, start.y, start.x)));
+
+    while let Some(Reverse((_, so_far, y, x))) = queue.pop() {
+        let point = Point::new(x, y);
+        if point == goal {
+            return Some(Path {
+                cost: so_far,
+                points: reconstruct(&came_from, start, goal),
+            });
+        }
+        // a cheaper way here was already expanded
+        if best.get(&point).is_some_and(|&b| b < so_far) {
+            continue;
+        }
+
+        for (next, _) in grid.neighbours_in(point, &Neighbourhood::VonNeumann) {
+            let Some(step) = cost(point, next) else {
+                continue;
+            };
+            let total = so_far + step;
+            if best.get(&next).is_none_or(|&b| total < b) {
+                best.insert(next, total);
+                came_from.insert(next, point);
+                queue.push(Reverse((
+                    total + heuristic(next, goal),
+                    total,
+                    next.y,
+                    next.x,
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+fn reconstruct(came_from: &HashMap<Point, Point>, start: Point, goal: Point) -> Vec<Point> {
+    let mut points = vec![goal];
+    let mut current = goal;
+    while current != start {
+        current = came_from[&current];
+        points.push(current);
+    }
+    points.reverse();
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::testing::Cell;
+    use crate::grid::{Grid, SparseGrid};
+    use crate::tasks::task::TaskInput;
+
+    const MAZE: &str = r#".....
+.###.
+...#.
+##.#.
+....."#;
+
+    fn walk(grid: &Grid<Cell>) -> impl Fn(Point, Point) -> Option<u64> + '_ {
+        |_, to| (grid[to] == Cell::Open).then_some(1)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid: Grid<Cell> = Grid::from_str(MAZE);
+        let distances = bfs(&grid, Point::new(0, 0), |_, cell| cell == Cell::Open);
+
+        assert_eq!(distances.get(&Point::new(0, 0)), Some(&0));
+        assert_eq!(distances.get(&Point::new(4, 0)), Some(&4));
+        assert_eq!(distances.get(&Point::new(2, 2)), Some(&4));
+        assert_eq!(distances.get(&Point::new(4, 4)), Some(&8));
+        assert_eq!(distances.get(&Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_bfs_unreachable() {
+        let grid: Grid<Cell> = Grid::from_str("..#.\n..#.");
+        let distances = bfs(&grid, Point::new(0, 0), |_, cell| cell == Cell::Open);
+
+        assert_eq!(distances.get(&Point::new(1, 1)), Some(&2));
+        assert_eq!(distances.get(&Point::new(3, 0)), None);
+        assert_eq!(distances.len(), 4);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let grid: Grid<Cell> = Grid::from_str(MAZE);
+        let (start, goal) = (Point::new(0, 2), Point::new(4, 4));
+
+        for path in [
+            dijkstra(&grid, start, goal, walk(&grid)).unwrap(),
+            astar(&grid, start, goal, walk(&grid)).unwrap(),
+        ] {
+            assert_eq!(path.cost, 6);
+            assert_eq!(path.points.len(), 7);
+            assert_eq!(path.points.first(), Some(&start));
+            assert_eq!(path.points.last(), Some(&goal));
+            for pair in path.points.windows(2) {
+                assert_eq!(pair[0].manhattan(&pair[1]), 1);
+                assert_eq!(grid[pair[1]], Cell::Open);
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_path() {
+        let grid: Grid<Cell> = Grid::from_str("..#.\n..#.");
+        let (start, goal) = (Point::new(0, 0), Point::new(3, 1));
+
+        assert_eq!(dijkstra(&grid, start, goal, walk(&grid)), None);
+        assert_eq!(astar(&grid, start, goal, walk(&grid)), None);
+        assert_eq!(astar(&grid, start, Point::new(9, 9), walk(&grid)), None);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // walking through the 9s is shorter but more expensive than going around
+        let grid = Grid::new(vec![
+            vec![1, 1, 1, 1, 1],
+            vec![1, 9, 9, 9, 1],
+            vec![1, 9, 9, 9, 1],
+            vec![1, 1, 1, 1, 1],
+        ]);
+        let cost = |_, to: Point| Some(grid[to]);
+        let (start, goal) = (Point::new(2, 0), Point::new(2, 3));
+
+        let path = dijkstra(&grid, start, goal, cost).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.points.len(), 8);
+        assert_eq!(astar(&grid, start, goal, cost).unwrap().cost, 7);
+        assert_eq!(
+            dijkstra(&grid, start, start, cost),
+            Some(Path {
+                cost: 0,
+                points: vec![start]
+            })
+        );
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let torus = Grid::filled(Point::new(10, 1), Cell::Open).wrapping();
+        let distances = bfs(&torus, Point::new(-1, 0), |_, cell| cell == Cell::Open);
+        assert_eq!(distances.len(), 10);
+        assert_eq!(distances.get(&Point::new(9, 0)), Some(&0));
+        assert_eq!(distances.get(&Point::new(4, 0)), Some(&5));
+
+        let path = dijkstra(&torus, Point::new(0, 0), Point::new(-1, 0), walk(&torus)).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.points, vec![Point::new(0, 0), Point::new(9, 0)]);
+    }
+
+    #[test]
+    fn test_astar_around_the_seam() {
+        // the step across the seam on the middle row is expensive, going around it
+        // through the top row is cheaper than the plain Manhattan distance suggests
+        let torus = Grid::filled(Point::new(10, 3), Cell::Open).wrapping();
+        let (start, goal) = (Point::new(0, 1), Point::new(9, 1));
+        let cost = |from: Point, to: Point| Some(if (from, to) == (start, goal) { 5 } else { 1 });
+
+        assert_eq!(dijkstra(&torus, start, goal, cost).unwrap().cost, 3);
+        assert_eq!(astar(&torus, start, goal, cost).unwrap().cost, 3);
+        assert_eq!(torus.manhattan(start, goal), 1);
+        assert_eq!(torus.manhattan(Point::new(1, 0), Point::new(8, 2)), 4);
+    }
+
+    #[test]
+    fn test_sparse() {
+        // a wall far away from the origin, the search stays inside the bounds
+        let mut walls = SparseGrid::new(false);
+        walls.replace(Point::new(-1000, -1000), false);
+        walls.replace(Point::new(-998, -1000), true);
+        walls.replace(Point::new(-998, -999), true);
+        walls.replace(Point::new(-996, -998), false);
+
+        let walk = |_, to| (!walls.get(to)).then_some(1);
+        let path = astar(
+            &walls,
+            Point::new(-1000, -1000),
+            Point::new(-996, -1000),
+            walk,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 8);
+
+        let distances = bfs(&walls, Point::new(-1000, -1000), |_, wall| !wall);
+        assert_eq!(distances.len(), 13);
+    }
+}
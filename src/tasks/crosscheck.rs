@@ -0,0 +1,297 @@
+// Runs every solver a day registers for a part on generated inputs and looks for
+// inputs where they don't agree, shrinking the first one found.
+
+use super::Registration;
+use super::panics;
+
+// how many seeds to try for every smaller size while minimizing
+const SEEDS_PER_SIZE: u64 = 20;
+
+#[derive(Debug)]
+pub struct Disagreement {
+    pub part: u32,
+    pub input: String,
+    // solver name with its answer, or with the panic message if it panicked
+    pub results: Vec<(&'static str, Result<String, String>)>,
+}
+
+// Returns None when all solvers agree on `runs` inputs of the given size, generated
+// from seeds starting at `seed`. Panics if the day has no input generator.
+pub fn crosscheck(
+    registration: &Registration,
+    size: usize,
+    seed: u64,
+    runs: u64,
+) -> Option<Disagreement> {
+    let generator = registration
+        .task
+        .generator()
+        .expect("crosscheck needs an input generator");
+
+    // solvers are expected to panic on some inputs, keep the output readable.
+    // This only quiets the current thread, the shared hook stays installed.
+    panics::silenced(|| {
+        for seed in seed..seed + runs {
+            let input = generator.generate(size, seed);
+            for part in [1, 2] {
+                if registration.task.solver_names(part).len() < 2 {
+                    continue;
+                }
+                if let Some(found) = check(registration, part, &input) {
+                    return Some(minimize(registration, found, size));
+                }
+            }
+        }
+        None
+    })
+}
+
+fn check(registration: &Registration, part: u32, input: &str) -> Option<Disagreement> {
+    let results: Vec<(&'static str, Result<String, String>)> = registration
+        .task
+        .solver_names(part)
+        .into_iter()
+        .map(|name| {
+            let result = panics::catch(|| registration.task.run_solver(input, part, name).unwrap());
+            (name, result.map_err(|panic| panic.to_string()))
+        })
+        .collect();
+
+    let agree = results.iter().all(|(_, r)| r.is_ok() && *r == results[0].1);
+    if agree {
+        return None;
+    }
+
+    Some(Disagreement {
+        part,
+        input: input.to_string(),
+        results,
+    })
+}
+
+// The same solvers have to panic for a smaller input to count as the same failure,
+// otherwise shrinking happily ends up on an input that is simply malformed.
+fn same_failure(a: &Disagreement, b: &Disagreement) -> bool {
+    a.results
+        .iter()
+        .zip(b.results.iter())
+        .all(|((_, a), (_, b))| a.is_err() == b.is_err())
+}
+
+fn minimize(registration: &Registration, found: Disagreement, size: usize) -> Disagreement {
+    let mut best = smallest_generated(registration, &found, size).unwrap_or(found);
+
+    // then drop lines (or comma separated entries for single line inputs) one at a time
+    let separator = if !best.input.trim().contains('\n') && best.input.contains(',') {
+        ","
+    } else {
+        "\n"
+    };
+
+    let mut shrunk = true;
+    while shrunk {
+        shrunk = false;
+        let units: Vec<&str> = best.input.split(separator).collect();
+        for i in (0..units.len()).rev() {
+            if units.len() == 1 {
+                break;
+            }
+            let candidate = units
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, unit)| *unit)
+                .collect::<Vec<_>>()
+                .join(separator);
+
+            if let Some(smaller) = check(registration, best.part, &candidate)
+                && same_failure(&best, &smaller)
+            {
+                best = smaller;
+                shrunk = true;
+                break;
+            }
+        }
+    }
+
+    best
+}
+
+// Regenerates inputs of increasing size until one shows the same failure.
+fn smallest_generated(
+    registration: &Registration,
+    found: &Disagreement,
+    size: usize,
+) -> Option<Disagreement> {
+    let generator = registration.task.generator()?;
+
+    for smaller_size in 1..size {
+        for seed in 0..SEEDS_PER_SIZE {
+            let input = generator.generate(smaller_size, seed);
+            if let Some(smaller) = check(registration, found.part, &input)
+                && same_failure(found, &smaller)
+            {
+                return Some(smaller);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::tasks::task::{InputGenerator, Solver, Task, TaskInput};
+
+    struct Numbers(Vec<u64>);
+
+    impl TaskInput for Numbers {
+        fn from_str(s: &str) -> Self {
+            Numbers(s.lines().map(|l| l.parse().unwrap()).collect())
+        }
+    }
+
+    struct NumbersGenerator;
+
+    impl InputGenerator for NumbersGenerator {
+        fn generate(&self, size: usize, seed: u64) -> String {
+            let mut rng = Rng::new(seed);
+            (0..size)
+                .map(|_| rng.range(0..100).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+
+    struct Sum;
+
+    impl Task for Sum {
+        type TaskInput = Numbers;
+
+        fn part1(&self, input: Numbers) -> String {
+            input.0.iter().sum::<u64>().to_string()
+        }
+
+        fn part2(&self, input: Numbers) -> String {
+            input.0.len().to_string()
+        }
+
+        fn generator(&self) -> Option<&'static dyn InputGenerator> {
+            Some(&NumbersGenerator)
+        }
+
+        fn solvers(&self) -> Vec<Solver<Self>> {
+            vec![
+                Solver::new("loop", 1, Sum::part1_loop),
+                Solver::new("skipping", 1, Sum::part1_skipping),
+            ]
+        }
+    }
+
+    impl Sum {
+        fn part1_loop(&self, input: Numbers) -> String {
+            let mut total = 0;
+            for n in input.0 {
+                total += n;
+            }
+            total.to_string()
+        }
+
+        // forgets about numbers ending with 7
+        fn part1_skipping(&self, input: Numbers) -> String {
+            input
+                .0
+                .iter()
+                .filter(|n| *n % 10 != 7)
+                .sum::<u64>()
+                .to_string()
+        }
+    }
+
+    #[test]
+    fn test_registered_solvers_agree() {
+        // rare cases like a full turn from zero on day 1 need this many runs to show
+        // up, one thread per day keeps it bearable
+        std::thread::scope(|scope| {
+            for registration in crate::tasks::registry().filter(|r| r.task.generator().is_some()) {
+                scope.spawn(move || {
+                    let result = crosscheck(registration, 50, 0, 300);
+                    assert!(
+                        result.is_none(),
+                        "{} day {}: {:?}",
+                        registration.year,
+                        registration.day,
+                        result
+                    );
+                });
+            }
+        });
+    }
+
+    struct Fragile;
+
+    impl Task for Fragile {
+        type TaskInput = Numbers;
+
+        fn part1(&self, input: Numbers) -> String {
+            input.0.len().to_string()
+        }
+
+        fn part2(&self, input: Numbers) -> String {
+            input.0.len().to_string()
+        }
+
+        fn generator(&self) -> Option<&'static dyn InputGenerator> {
+            Some(&NumbersGenerator)
+        }
+
+        fn solvers(&self) -> Vec<Solver<Self>> {
+            vec![Solver::new("fragile", 1, Fragile::part1_fragile)]
+        }
+    }
+
+    impl Fragile {
+        fn part1_fragile(&self, _input: Numbers) -> String {
+            panic!("fragile solver")
+        }
+    }
+
+    #[test]
+    fn test_panics_keep_their_location_across_threads() {
+        let run = || {
+            let registration = Registration::new(0, 1, &Fragile);
+            for seed in 0..20 {
+                let disagreement = crosscheck(&registration, 5, seed, 1).expect("solver panics");
+                let (name, result) = &disagreement.results[1];
+                assert_eq!(*name, "fragile");
+                let message = result.as_ref().unwrap_err();
+                assert!(
+                    message.starts_with("fragile solver (at src/tasks/crosscheck.rs:"),
+                    "{}",
+                    message
+                );
+            }
+        };
+
+        std::thread::scope(|scope| {
+            let first = scope.spawn(run);
+            let second = scope.spawn(run);
+            first.join().unwrap();
+            second.join().unwrap();
+        });
+    }
+
+    #[test]
+    fn test_disagreement_is_minimized() {
+        let registration = Registration::new(0, 1, &Sum);
+
+        let disagreement = crosscheck(&registration, 30, 0, 10).expect("solvers disagree");
+
+        assert_eq!(disagreement.part, 1);
+        assert_eq!(disagreement.input.lines().count(), 1);
+        assert!(disagreement.input.ends_with('7'));
+        assert_eq!(disagreement.results.len(), 3);
+    }
+}
@@ -0,0 +1,282 @@
+// A small random mutation fuzzer for the input parsers. There is one target per
+// registered day (its TaskInput::from_str) plus one for Grid::from_str, each seeded
+// with the example inputs. Inputs that parse fine are kept in the corpus so that
+// mutations keep building on something close to valid.
+
+use std::collections::HashSet;
+
+use super::panics::{self, Panic};
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::tasks::task::TaskInput;
+
+const MAX_CORPUS: usize = 1000;
+
+// characters that mean something to at least one of the parsers
+const INTERESTING_CHARS: &[char] = &[
+    '0', '1', '9', '\n', ' ', ',', '-', '.', '@', '#', '^', 'S', 'L', 'R', '+', '*', '[', ']', '(',
+    ')', '{', '}',
+];
+
+const INTERESTING_TOKENS: &[&str] = &[
+    "",
+    "\n\n",
+    "-1",
+    "00",
+    "18446744073709551615",
+    "18446744073709551616",
+    "99999999999999999999999",
+];
+
+pub struct Target {
+    pub name: String,
+    pub corpus: Vec<&'static str>,
+    parse: Box<dyn Fn(&str) + Sync>,
+}
+
+impl Target {
+    pub fn parse(&self, input: &str) -> Result<(), Panic> {
+        panics::catch(|| (self.parse)(input))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Crash {
+    pub input: String,
+    pub panic: Panic,
+}
+
+// Grid cell accepting any character, so the grid target only exercises Grid itself
+#[derive(Clone, Copy)]
+struct AnyCell;
+
+impl TaskInput for AnyCell {
+    fn from_str(_: &str) -> Self {
+        AnyCell
+    }
+}
+
+pub fn targets() -> Vec<Target> {
+    let mut targets: Vec<Target> = super::registry()
+        .map(|registration| Target {
+            name: format!("{}-day{}", registration.year, registration.day),
+            corpus: registration
+                .task
+                .examples()
+                .iter()
+                .map(|e| e.input)
+                .collect(),
+            parse: Box::new(|input| registration.task.parse_str(input)),
+        })
+        .collect();
+
+    targets.push(Target {
+        name: "grid".to_string(),
+        // only the examples that are grids themselves
+        corpus: super::registry()
+            .flat_map(|r| r.task.examples().iter().map(|e| e.input))
+            .filter(|input| {
+                let mut widths = input.lines().map(|line| line.trim().chars().count());
+                let first = widths.next();
+                first.is_some() && widths.all(|width| Some(width) == first)
+            })
+            .collect(),
+        parse: Box::new(|input| {
+            Grid::<AnyCell>::from_str(input);
+        }),
+    });
+
+    targets
+}
+
+pub fn find_target(name: &str) -> Option<Target> {
+    targets().into_iter().find(|target| target.name == name)
+}
+
+// Runs `iterations` mutated inputs through the target and returns one crash
+// per distinct panic location, plus every panic whose location is unknown.
+pub fn fuzz(target: &Target, iterations: u64, seed: u64) -> Vec<Crash> {
+    let mut mutator = Mutator::new(seed);
+    let mut corpus: Vec<String> = target.corpus.iter().map(|s| s.to_string()).collect();
+    if corpus.is_empty() {
+        corpus.push(String::new());
+    }
+    let mut seen: HashSet<String> = corpus.iter().cloned().collect();
+
+    let mut crashes: Vec<Crash> = vec![];
+
+    panics::silenced(|| {
+        for _ in 0..iterations {
+            let base = mutator.rng.choose(&corpus).clone();
+            let input = mutator.mutate(&base, &corpus);
+            if !seen.insert(input.clone()) {
+                continue;
+            }
+
+            match target.parse(&input) {
+                Ok(()) => {
+                    if corpus.len() < MAX_CORPUS {
+                        corpus.push(input);
+                    }
+                }
+                Err(panic) => {
+                    let known = crashes.iter().any(|crash| same_crash(&crash.panic, &panic));
+                    if !known {
+                        crashes.push(shrink(target, Crash { input, panic }));
+                    }
+                }
+            }
+        }
+    });
+
+    crashes
+}
+
+// Panics are told apart by where they happened. Without a location there's
+// nothing to go by, so such a panic is never the same as another one.
+fn same_crash(a: &Panic, b: &Panic) -> bool {
+    a.location.is_some() && a.location == b.location
+}
+
+// Removes chunks of the crashing input, from big to single characters,
+// as long as it still panics at the same place.
+fn shrink(target: &Target, crash: Crash) -> Crash {
+    let mut best = crash;
+    let mut chunk = best.input.chars().count() / 2;
+
+    while chunk > 0 {
+        let chars: Vec<char> = best.input.chars().collect();
+        let mut shrunk = false;
+
+        for start in (0..chars.len().saturating_sub(chunk - 1)).step_by(chunk) {
+            let candidate: String = chars[..start]
+                .iter()
+                .chain(chars[start + chunk..].iter())
+                .collect();
+            if let Err(panic) = target.parse(&candidate)
+                && same_crash(&panic, &best.panic)
+            {
+                best = Crash {
+                    input: candidate,
+                    panic,
+                };
+                shrunk = true;
+                break;
+            }
+        }
+
+        if !shrunk {
+            chunk /= 2;
+        }
+    }
+
+    best
+}
+
+pub struct Mutator {
+    rng: Rng,
+}
+
+impl Mutator {
+    pub fn new(seed: u64) -> Self {
+        Mutator {
+            rng: Rng::new(seed),
+        }
+    }
+
+    // Applies a few random edits to `input`, sometimes splicing in another corpus entry.
+    pub fn mutate(&mut self, input: &str, corpus: &[String]) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+
+        for _ in 0..self.rng.range_inclusive(1..=4) {
+            let at = self.rng.range_inclusive(0..=chars.len() as u64) as usize;
+            match self.rng.range(0..7) {
+                0 if at < chars.len() => {
+                    let len = (self.rng.range_inclusive(1..=4) as usize).min(chars.len() - at);
+                    chars.drain(at..at + len);
+                }
+                1 => chars.insert(at, *self.rng.choose(INTERESTING_CHARS)),
+                2 if at < chars.len() => chars[at] = *self.rng.choose(INTERESTING_CHARS),
+                3 if at < chars.len() => {
+                    let len = (self.rng.range_inclusive(1..=16) as usize).min(chars.len() - at);
+                    let chunk: Vec<char> = chars[at..at + len].to_vec();
+                    let to = self.rng.range_inclusive(0..=chars.len() as u64) as usize;
+                    chars.splice(to..to, chunk);
+                }
+                4 => {
+                    let token = self.rng.choose(INTERESTING_TOKENS);
+                    chars.splice(at..at, token.chars());
+                }
+                5 if !corpus.is_empty() => {
+                    let other: Vec<char> = self.rng.choose(corpus).chars().collect();
+                    let from = self.rng.range_inclusive(0..=other.len() as u64) as usize;
+                    chars.truncate(at);
+                    chars.extend_from_slice(&other[from..]);
+                }
+                6 => chars.truncate(at),
+                _ => {
+                    let random = char::from(self.rng.range(0x20..0x7f) as u8);
+                    chars.insert(at, random);
+                }
+            }
+        }
+
+        chars.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_locations_are_never_the_same_crash() {
+        let panic = |location: Option<&str>| Panic {
+            message: "boom".to_string(),
+            location: location.map(str::to_string),
+        };
+
+        assert!(same_crash(&panic(Some("a.rs:1")), &panic(Some("a.rs:1"))));
+        assert!(!same_crash(&panic(Some("a.rs:1")), &panic(Some("a.rs:2"))));
+        assert!(!same_crash(&panic(None), &panic(None)));
+        assert!(!same_crash(&panic(None), &panic(Some("a.rs:1"))));
+    }
+
+    #[test]
+    fn test_every_target_has_a_corpus() {
+        let targets = targets();
+
+        assert_eq!(targets.len(), super::super::registry().count() + 1);
+        for target in targets.iter() {
+            assert!(!target.corpus.is_empty(), "{}", target.name);
+            for input in target.corpus.iter() {
+                assert!(target.parse(input).is_ok(), "{}", target.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mutator_is_deterministic() {
+        let corpus = vec!["1,2,3".to_string()];
+        let a: Vec<String> = (0..10)
+            .scan(Mutator::new(1), |m, _| Some(m.mutate("L68\nR10", &corpus)))
+            .collect();
+        let b: Vec<String> = (0..10)
+            .scan(Mutator::new(1), |m, _| Some(m.mutate("L68\nR10", &corpus)))
+            .collect();
+
+        assert_eq!(a, b);
+        assert!(a.iter().any(|m| m != "L68\nR10"));
+    }
+
+    #[test]
+    fn test_fuzz_finds_crashes() {
+        let target = find_target("2025-day1").unwrap();
+        let crashes = fuzz(&target, 200, 0);
+
+        assert!(!crashes.is_empty());
+        for crash in crashes {
+            assert!(target.parse(&crash.input).is_err());
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Harness for the golden test suite: every registered day carries its puzzle examples,
+// and `golden_tests!` turns them into one test per (year, day, part) that checks all
+// of the part's solvers.
+
+pub fn check(year: u32, day: u32, part: u32) {
+    let registration =
+        super::find(year, day).unwrap_or_else(|| panic!("{} day {} is not registered", year, day));
+
+    for (i, example) in registration.task.examples().iter().enumerate() {
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+        for solver in registration.task.solver_names(part) {
+            let result = registration.task.run_solver(example.input, part, solver);
+            assert_eq!(
+                result.as_deref(),
+                Some(expected),
+                "{} day {} part {} ({} solver) failed on example #{}",
+                year,
+                day,
+                part,
+                solver,
+                i
+            );
+        }
+    }
+}
+
+// Registered days that don't have a single example to check against.
+pub fn days_without_examples() -> Vec<(u32, u32)> {
+    super::registry()
+        .filter(|registration| registration.task.examples().is_empty())
+        .map(|registration| (registration.year, registration.day))
+        .collect()
+}
+
+#[macro_export]
+macro_rules! golden_tests {
+    ($($year_mod:ident = $year:literal { $($day_mod:ident = $day:literal),* $(,)? })*) => {
+        pub const GOLDEN_DAYS: &[(u32, u32)] = &[$($(($year, $day),)*)*];
+
+        $(
+            mod $year_mod {
+                $(
+                    mod $day_mod {
+                        #[test]
+                        fn part1() {
+                            $crate::tasks::golden::check($year, $day, 1);
+                        }
+
+                        #[test]
+                        fn part2() {
+                            $crate::tasks::golden::check($year, $day, 2);
+                        }
+                    }
+                )*
+            }
+        )*
+    };
+}
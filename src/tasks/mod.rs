@@ -1,13 +1,54 @@
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-
-pub mod day10;
+pub mod y2025;
 
+pub mod crosscheck;
+pub mod fuzz;
+pub mod golden;
+pub mod panics;
 pub mod task;
+
+use task::Runnable;
+
+// A single (year, day) entry in the registry. Each season keeps its own list of
+// registrations next to its days, and `registry` chains them together.
+pub struct Registration {
+    pub year: u32,
+    pub day: u32,
+    pub task: &'static dyn Runnable,
+}
+
+impl Registration {
+    pub const fn new(year: u32, day: u32, task: &'static dyn Runnable) -> Self {
+        Registration { year, day, task }
+    }
+}
+
+const SEASONS: &[&[Registration]] = &[y2025::TASKS];
+
+pub fn registry() -> impl Iterator<Item = &'static Registration> {
+    SEASONS.iter().flat_map(|season| season.iter())
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Registration> {
+    registry().find(|registration| registration.year == year && registration.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_has_unique_entries() {
+        let mut keys: Vec<(u32, u32)> = registry().map(|r| (r.year, r.day)).collect();
+        let total = keys.len();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), total);
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2025, 4).map(|r| r.day), Some(4));
+        assert!(find(2025, 26).is_none());
+        assert!(find(2015, 1).is_none());
+    }
+}
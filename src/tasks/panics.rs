@@ -0,0 +1,128 @@
+// Helpers for running code that is expected to panic (solvers on odd inputs,
+// parsers under fuzzing) without flooding stderr with panic messages.
+
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    // how many silenced() calls this thread is inside of
+    static SILENCED: Cell<usize> = const { Cell::new(0) };
+}
+
+static HOOK: Once = Once::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    // file:line of the panic, if known
+    pub location: Option<String>,
+}
+
+impl std::fmt::Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} (at {})", self.message, location),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+// The panic hook is global, so it's installed once and never swapped: it records
+// where every panic happened for the panicking thread, and hands the panic to the
+// previous hook unless that thread is silenced.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let location = info
+                .location()
+                .map(|l| format!("{}:{}", l.file(), l.line()));
+            LOCATION.with(|cell| *cell.borrow_mut() = location);
+
+            if SILENCED.with(|silenced| silenced.get()) == 0 {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// Restores the silenced count even when the silenced code panics
+struct SilencedGuard;
+
+impl Drop for SilencedGuard {
+    fn drop(&mut self) {
+        SILENCED.with(|silenced| silenced.set(silenced.get() - 1));
+    }
+}
+
+// Runs `f` without printing the panics of the current thread. Other threads
+// keep reporting theirs as usual.
+pub fn silenced<R>(f: impl FnOnce() -> R) -> R {
+    install_hook();
+    SILENCED.with(|silenced| silenced.set(silenced.get() + 1));
+    let _guard = SilencedGuard;
+    f()
+}
+
+pub fn catch<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    install_hook();
+    LOCATION.with(|cell| *cell.borrow_mut() = None);
+
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "panicked".to_string()
+        };
+
+        Panic {
+            message,
+            location: LOCATION.with(|cell| cell.borrow_mut().take()),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        let result = silenced(|| catch(|| -> u32 { panic!("boom {}", 42) }));
+        let panic = result.unwrap_err();
+
+        assert_eq!(panic.message, "boom 42");
+        assert!(panic.location.unwrap().starts_with(file!()));
+        assert_eq!(silenced(|| catch(|| 7)), Ok(7));
+    }
+
+    #[test]
+    fn test_silenced_from_two_threads() {
+        let run = |id: u32| {
+            silenced(|| {
+                for i in 0..200 {
+                    let panic = catch(|| -> u32 { panic!("thread {} run {}", id, i) }).unwrap_err();
+                    assert_eq!(panic.message, format!("thread {} run {}", id, i));
+                    assert!(panic.location.unwrap().starts_with(file!()));
+                }
+            })
+        };
+
+        std::thread::scope(|scope| {
+            let first = scope.spawn(|| run(1));
+            let second = scope.spawn(|| run(2));
+            first.join().unwrap();
+            second.join().unwrap();
+        });
+    }
+
+    #[test]
+    fn test_location_without_silencing() {
+        let panic = catch(|| -> u32 { panic!("loud") }).unwrap_err();
+        assert!(panic.location.unwrap().starts_with(file!()));
+    }
+}
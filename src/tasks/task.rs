@@ -1,9 +1,50 @@
+use std::io;
+
+use crate::grid::Frames;
+
+pub const DEFAULT_SOLVER: &str = "default";
+
 pub trait Task {
     type TaskInput: TaskInput;
 
     fn part1(&self, input: Self::TaskInput) -> String;
     fn part2(&self, input: Self::TaskInput) -> String;
 
+    // Puzzle examples with their known answers, checked by the golden test suite.
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    // Random valid inputs for stress testing, for days that know how to make them.
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        None
+    }
+
+    // Alternative implementations of the parts, usually naive references kept around
+    // to crosscheck the optimized part1/part2, which are registered as DEFAULT_SOLVER.
+    fn solvers(&self) -> Vec<Solver<Self>>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    // Writes images of the solution as it progresses, None for days without one.
+    fn visualise(&self, _input: Self::TaskInput, _frames: &mut Frames) -> Option<io::Result<()>> {
+        None
+    }
+
+    // Writes a text account of how the solution progresses, None for days without one.
+    // `colour` allows terminal escape codes in it.
+    fn trace(
+        &self,
+        _input: Self::TaskInput,
+        _out: &mut dyn io::Write,
+        _colour: bool,
+    ) -> Option<io::Result<()>> {
+        None
+    }
+
     fn run(&self, input_str: &str, part: u32) -> String {
         let input = Self::TaskInput::from_str(input_str);
         match part {
@@ -12,8 +53,120 @@ pub trait Task {
             _ => panic!("Invalid part"),
         }
     }
+
+    // None if the task has no solver with this name for the part
+    fn run_solver(&self, input_str: &str, part: u32, name: &str) -> Option<String>
+    where
+        Self: Sized,
+    {
+        if name == DEFAULT_SOLVER {
+            return Some(self.run(input_str, part));
+        }
+
+        let solver = self
+            .solvers()
+            .into_iter()
+            .find(|solver| solver.part == part && solver.name == name)?;
+        Some((solver.solve)(self, Self::TaskInput::from_str(input_str)))
+    }
+}
+
+pub struct Solver<T: Task> {
+    pub name: &'static str,
+    pub part: u32,
+    pub solve: fn(&T, T::TaskInput) -> String,
+}
+
+impl<T: Task> Solver<T> {
+    pub fn new(name: &'static str, part: u32, solve: fn(&T, T::TaskInput) -> String) -> Self {
+        Solver { name, part, solve }
+    }
 }
 
 pub trait TaskInput {
     fn from_str(s: &str) -> Self;
 }
+
+// An example input from the puzzle text. A part is left as None when the example
+// can't be checked against it (e.g. the part isn't solved yet).
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u32) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("Invalid part"),
+        }
+    }
+}
+
+// Produces a valid puzzle input. `size` is the day's natural unit of input size
+// (lines, grid side, polygon vertices...) and the same seed always gives the same input.
+pub trait InputGenerator: Sync {
+    fn generate(&self, size: usize, seed: u64) -> String;
+}
+
+// Object-safe view of a Task, so tasks with different inputs can share the registry.
+pub trait Runnable: Sync {
+    fn run_str(&self, input_str: &str, part: u32) -> String;
+    // only parses the input, for fuzzing the TaskInput
+    fn parse_str(&self, input_str: &str);
+    fn examples(&self) -> &'static [Example];
+    fn generator(&self) -> Option<&'static dyn InputGenerator>;
+    fn solver_names(&self, part: u32) -> Vec<&'static str>;
+    fn run_solver(&self, input_str: &str, part: u32, name: &str) -> Option<String>;
+    fn visualise_str(&self, input_str: &str, frames: &mut Frames) -> Option<io::Result<()>>;
+    fn trace_str(
+        &self,
+        input_str: &str,
+        out: &mut dyn io::Write,
+        colour: bool,
+    ) -> Option<io::Result<()>>;
+}
+
+impl<T: Task + Sync> Runnable for T {
+    fn run_str(&self, input_str: &str, part: u32) -> String {
+        self.run(input_str, part)
+    }
+
+    fn parse_str(&self, input_str: &str) {
+        T::TaskInput::from_str(input_str);
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        Task::examples(self)
+    }
+
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        Task::generator(self)
+    }
+
+    fn solver_names(&self, part: u32) -> Vec<&'static str> {
+        let alternatives = self.solvers().into_iter().filter(|s| s.part == part);
+        std::iter::once(DEFAULT_SOLVER)
+            .chain(alternatives.map(|s| s.name))
+            .collect()
+    }
+
+    fn run_solver(&self, input_str: &str, part: u32, name: &str) -> Option<String> {
+        Task::run_solver(self, input_str, part, name)
+    }
+
+    fn visualise_str(&self, input_str: &str, frames: &mut Frames) -> Option<io::Result<()>> {
+        self.visualise(T::TaskInput::from_str(input_str), frames)
+    }
+
+    fn trace_str(
+        &self,
+        input_str: &str,
+        out: &mut dyn io::Write,
+        colour: bool,
+    ) -> Option<io::Result<()>> {
+        self.trace(T::TaskInput::from_str(input_str), out, colour)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::rng::Rng;
+use crate::tasks::task::InputGenerator;
+
+// `size` rotations of the dial, each up to a few full turns in either direction.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size)
+            .map(|_| {
+                let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+                format!("{}{}", direction, rng.range_inclusive(1..=999))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,216 @@
+mod generator;
+
+use crate::direction::Direction;
+use crate::tasks::task::{self, Example, InputGenerator, Solver};
+
+#[derive(Debug)]
+struct Entry {
+    direction: Direction,
+    distance: u32,
+}
+
+pub struct Input {
+    entries: Vec<Entry>,
+}
+
+impl task::TaskInput for Input {
+    fn from_str(s: &str) -> Self {
+        let entries = s
+            .lines()
+            .map(|line| {
+                let mut chars = line.chars();
+                // the dial is a line of numbers, L goes towards the lower ones
+                let direction = chars
+                    .next()
+                    .and_then(Direction::from_char)
+                    .filter(|direction| direction.offset().y == 0)
+                    .expect("Invalid rotation");
+                let distance = chars.as_str().parse::<u32>().unwrap();
+                Entry {
+                    direction,
+                    distance,
+                }
+            })
+            .collect();
+        Input { entries }
+    }
+}
+
+const EXAMPLE_INPUT: &str = r#"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82"#;
+
+// Found by crosscheck: a full turn right starting from zero counted the final
+// landing twice.
+const FULL_TURN_FROM_ZERO_INPUT: &str = r#"L50
+R100"#;
+
+pub struct Task {}
+
+impl task::Task for Task {
+    type TaskInput = Input;
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                input: EXAMPLE_INPUT,
+                part1: Some("3"),
+                part2: Some("6"),
+            },
+            Example {
+                input: FULL_TURN_FROM_ZERO_INPUT,
+                part1: Some("2"),
+                part2: Some("2"),
+            },
+        ]
+    }
+
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        Some(&generator::Generator)
+    }
+
+    fn solvers(&self) -> Vec<Solver<Self>> {
+        vec![
+            Solver::new("click-by-click", 1, Task::part1_click_by_click),
+            Solver::new("click-by-click", 2, Task::part2_click_by_click),
+        ]
+    }
+
+    fn part1(&self, input: Self::TaskInput) -> String {
+        let mut zeroes_counter = 0;
+
+        let mut position = 50;
+        for entry in input.entries {
+            let new_raw_position = self.next_raw_position(position, &entry);
+            (position, _) = adjust_position(position as i64, new_raw_position);
+            if position == 0 {
+                zeroes_counter += 1;
+            }
+        }
+        zeroes_counter.to_string()
+    }
+
+    fn part2(&self, input: Self::TaskInput) -> String {
+        let mut zero_clicks_total = 0;
+
+        let mut position = 50;
+        for entry in input.entries {
+            let raw_position = self.next_raw_position(position, &entry);
+            let (new_position, zero_clicks) = adjust_position(position as i64, raw_position);
+            position = new_position;
+            if position == 0 {
+                zero_clicks_total += 1;
+            }
+            zero_clicks_total += zero_clicks;
+        }
+        zero_clicks_total.to_string()
+    }
+}
+
+impl Task {
+    fn next_raw_position(&self, current_position: u32, entry: &Entry) -> i64 {
+        current_position as i64 + entry.direction.offset().x * entry.distance as i64
+    }
+
+    fn part1_click_by_click(&self, input: Input) -> String {
+        Self::count_zeroes_by_clicks(input).0.to_string()
+    }
+
+    fn part2_click_by_click(&self, input: Input) -> String {
+        Self::count_zeroes_by_clicks(input).1.to_string()
+    }
+
+    // Reference solution turning the dial one click at a time.
+    // Returns how many rotations ended at zero and how many clicks landed on it.
+    fn count_zeroes_by_clicks(input: Input) -> (u32, u32) {
+        let mut position: i64 = 50;
+        let (mut rotations, mut clicks) = (0, 0);
+
+        for entry in input.entries {
+            let step = entry.direction.offset().x;
+            for _ in 0..entry.distance {
+                position = (position + step).rem_euclid(100);
+                if position == 0 {
+                    clicks += 1;
+                }
+            }
+            if position == 0 {
+                rotations += 1;
+            }
+        }
+
+        (rotations, clicks)
+    }
+}
+
+fn adjust_position(old: i64, new: i64) -> (u32, u32) {
+    let mut zero_clicks = 0;
+    if new < 0 {
+        let mut position = new;
+        while position < 0 {
+            position += 100;
+            zero_clicks += 1;
+        }
+        if old == 0 {
+            zero_clicks -= 1;
+        }
+        (position as u32, zero_clicks)
+    } else if new > 99 {
+        let mut position = new;
+        while position > 99 {
+            position -= 100;
+            zero_clicks += 1;
+        }
+        // landing on zero is counted by the caller
+        if position == 0 {
+            zero_clicks -= 1;
+        }
+        (position as u32, zero_clicks)
+    } else {
+        (new as u32, zero_clicks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjust_position() {
+        let tests = vec![
+            ((0, 210), (10, 2)),
+            ((0, -10), (90, 0)),
+            ((50, 60), (60, 0)),
+            ((99, 1), (1, 0)),
+            ((0, 100), (0, 0)),
+            ((95, 155), (55, 1)),
+            ((50, 1050), (50, 10)),
+            ((52, 100), (0, 0)),
+        ];
+        for ((old, new), expected) in tests {
+            let result = adjust_position(old, new);
+            assert_eq!(result, expected, "Failed for old: {}, new: {}", old, new);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = <Input as task::TaskInput>::from_str("L68\nR5");
+        assert_eq!(input.entries[0].direction, Direction::West);
+        assert_eq!(input.entries[1].direction, Direction::East);
+        assert_eq!(input.entries[1].distance, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid rotation")]
+    fn test_parse_vertical_rotation() {
+        <Input as task::TaskInput>::from_str("U5");
+    }
+}
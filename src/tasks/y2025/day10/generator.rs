@@ -0,0 +1,79 @@
+use crate::rng::Rng;
+use crate::tasks::task::InputGenerator;
+
+// `size` machines. Desired indicators and joltages are produced by actually pressing
+// a random selection of buttons, so every machine has a solution for both parts.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size)
+            .map(|_| Self::machine(&mut rng))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Generator {
+    fn machine(rng: &mut Rng) -> String {
+        let indicators_count = rng.range_inclusive(3..=10) as usize;
+        let buttons_count = rng.range_inclusive(2..=indicators_count as u64 + 3) as usize;
+
+        let buttons: Vec<Vec<usize>> = (0..buttons_count)
+            .map(|_| {
+                loop {
+                    let button: Vec<usize> =
+                        (0..indicators_count).filter(|_| rng.chance(0.4)).collect();
+                    if !button.is_empty() {
+                        break button;
+                    }
+                }
+            })
+            .collect();
+
+        let mut indicators = vec![false; indicators_count];
+        while indicators.iter().all(|&on| !on) {
+            for button in buttons.iter() {
+                if rng.chance(0.5) {
+                    for &i in button {
+                        indicators[i] = !indicators[i];
+                    }
+                }
+            }
+        }
+
+        let mut joltages = vec![0; indicators_count];
+        for button in buttons.iter() {
+            let presses = rng.range_inclusive(0..=20) as usize;
+            for &i in button {
+                joltages[i] += presses;
+            }
+        }
+
+        let indicators: String = indicators
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect();
+        let buttons: Vec<String> = buttons
+            .iter()
+            .map(|button| format!("({})", join(button)))
+            .collect();
+
+        format!(
+            "[{}] {} {{{}}}",
+            indicators,
+            buttons.join(" "),
+            join(&joltages)
+        )
+    }
+}
+
+fn join(values: &[usize]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,167 @@
+mod generator;
+mod input;
+use std::collections::HashSet;
+
+use crate::tasks::task::{Example, InputGenerator, Solver};
+
+use input::*;
+
+pub struct Machine {
+    desired_indicators: Vec<Indicator>,
+    buttons: Vec<Vec<usize>>,
+    // not used until part 2 is solved
+    #[allow(dead_code)]
+    joltage_requirements: Vec<usize>,
+}
+
+const EXAMPLE_INPUT: &str = r#"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}"#;
+
+pub struct Task {}
+
+impl crate::tasks::task::Task for Task {
+    type TaskInput = Input;
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            part1: Some("7"),
+            // part 2 isn't solved yet
+            part2: None,
+        }]
+    }
+
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        Some(&generator::Generator)
+    }
+
+    fn solvers(&self) -> Vec<Solver<Self>> {
+        vec![Solver::new("subsets", 1, Task::part1_subsets)]
+    }
+
+    fn part1(&self, input: Self::TaskInput) -> String {
+        input
+            .machines
+            .iter()
+            .map(|input_machine| {
+                let machine = Machine {
+                    desired_indicators: input_machine.indicators.clone(),
+                    buttons: input_machine.buttons.clone(),
+                    joltage_requirements: input_machine.joltage_requirements.clone(),
+                };
+
+                machine.get_button_presses()
+            })
+            .sum::<usize>()
+            .to_string()
+    }
+
+    fn part2(&self, _input: Self::TaskInput) -> String {
+        unimplemented!()
+    }
+}
+
+impl Task {
+    // Pressing a button twice cancels out, so every answer is a set of buttons pressed once.
+    // Try all of them and take the smallest set that lights up the right indicators.
+    fn part1_subsets(&self, input: Input) -> String {
+        input
+            .machines
+            .iter()
+            .map(|machine| {
+                let target = as_mask(
+                    machine
+                        .indicators
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, indicator)| **indicator == Indicator::On)
+                        .map(|(i, _)| i),
+                );
+                let buttons: Vec<u64> = machine
+                    .buttons
+                    .iter()
+                    .map(|button| as_mask(button.iter().copied()))
+                    .collect();
+
+                (0u64..1 << buttons.len())
+                    .filter(|subset| {
+                        let lit = buttons
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| subset & (1 << i) != 0)
+                            .fold(0, |state, (_, button)| state ^ button);
+                        lit == target
+                    })
+                    .map(|subset| subset.count_ones() as usize)
+                    .min()
+                    .expect("indicators can't be reached")
+            })
+            .sum::<usize>()
+            .to_string()
+    }
+}
+
+fn as_mask(indices: impl Iterator<Item = usize>) -> u64 {
+    indices.fold(0, |mask, i| mask | (1 << i))
+}
+
+impl Machine {
+    fn get_button_presses(&self) -> usize {
+        let current_state: Vec<Indicator> = vec![Indicator::Off; self.desired_indicators.len()];
+
+        let mut visited_states: HashSet<Vec<Indicator>> = HashSet::new();
+
+        let mut surface = HashSet::new();
+        surface.insert(current_state);
+
+        for i in 1.. {
+            let mut next_surface = HashSet::new();
+
+            for state in surface.iter() {
+                for button in self.buttons.iter() {
+                    let new_state = self.press_button(button, state);
+                    if new_state == self.desired_indicators {
+                        return i;
+                    }
+
+                    if !visited_states.contains(&new_state) {
+                        next_surface.insert(new_state.clone());
+                        visited_states.insert(new_state);
+                    }
+                }
+            }
+
+            visited_states.extend(surface);
+            surface = next_surface;
+        }
+
+        unreachable!()
+    }
+
+    fn press_button(&self, button: &[usize], current_state: &[Indicator]) -> Vec<Indicator> {
+        let mut new_state = current_state.to_vec();
+        for &indicator_index in button {
+            new_state[indicator_index] = match new_state[indicator_index] {
+                Indicator::On => Indicator::Off,
+                Indicator::Off => Indicator::On,
+            };
+        }
+        new_state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tasks::task::Task;
+    use crate::tasks::task::TaskInput;
+
+    #[test]
+    fn test_part1() {
+        let task = super::Task {};
+        let input = Input::from_str(EXAMPLE_INPUT);
+        let result = task.part1(input);
+        assert_eq!(result, "7");
+    }
+}
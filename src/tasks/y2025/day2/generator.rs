@@ -0,0 +1,22 @@
+use crate::rng::Rng;
+use crate::tasks::task::InputGenerator;
+
+// `size` comma separated ID ranges. Starts have 1 to 10 digits and every range
+// spans at most a few thousand IDs, so brute force solvers stay usable.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size)
+            .map(|_| {
+                let digits = rng.range_inclusive(1..=10) as u32;
+                let start = rng.range(10u64.pow(digits - 1)..10u64.pow(digits));
+                let end = start + rng.range_inclusive(0..=5000);
+                format!("{}-{}", start, end)
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
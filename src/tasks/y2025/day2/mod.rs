@@ -1,10 +1,35 @@
-use crate::tasks::task::TaskInput;
+mod generator;
+
+use std::collections::HashSet;
+
+use crate::tasks::task::{Example, InputGenerator, Solver, TaskInput};
+
+const EXAMPLE_INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
 
 pub struct Task;
 
 impl crate::tasks::task::Task for Task {
     type TaskInput = Input;
 
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            part1: Some("1227775554"),
+            part2: Some("4174379265"),
+        }]
+    }
+
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        Some(&generator::Generator)
+    }
+
+    fn solvers(&self) -> Vec<Solver<Self>> {
+        vec![
+            Solver::new("patterns", 1, Task::part1_patterns),
+            Solver::new("patterns", 2, Task::part2_patterns),
+        ]
+    }
+
     fn part1(&self, input: Self::TaskInput) -> String {
         let sum: u64 = input
             .ranges
@@ -29,8 +54,28 @@ impl crate::tasks::task::Task for Task {
     }
 }
 
+impl Task {
+    fn part1_patterns(&self, input: Input) -> String {
+        sum_repeated_ids(&input.ranges, |digits| {
+            if digits % 2 == 0 {
+                vec![digits / 2]
+            } else {
+                vec![]
+            }
+        })
+        .to_string()
+    }
+
+    fn part2_patterns(&self, input: Input) -> String {
+        sum_repeated_ids(&input.ranges, |digits| {
+            (1..=digits / 2).filter(|k| digits % k == 0).collect()
+        })
+        .to_string()
+    }
+}
+
 pub struct Input {
-    ranges: Vec<std::ops::Range<u64>>,
+    ranges: Vec<std::ops::RangeInclusive<u64>>,
 }
 
 impl TaskInput for Input {
@@ -49,7 +94,7 @@ impl TaskInput for Input {
                     .expect("Expected end of range")
                     .parse::<u64>()
                     .unwrap();
-                start..end
+                start..=end
             })
             .collect();
         Input { ranges }
@@ -59,7 +104,7 @@ impl TaskInput for Input {
 fn is_valid(id: u64) -> bool {
     let num_digits = id.to_string().len();
 
-    if num_digits % 2 != 0 {
+    if !num_digits.is_multiple_of(2) {
         return true;
     }
 
@@ -75,7 +120,7 @@ fn is_valid_for_p2(id: u64) -> bool {
     let num_digits = id.to_string().len();
 
     for i in 1..=num_digits / 2 {
-        if num_digits % i != 0 {
+        if !num_digits.is_multiple_of(i) {
             continue;
         }
 
@@ -90,6 +135,42 @@ fn is_valid_for_p2(id: u64) -> bool {
     true
 }
 
+// Instead of checking every ID in the ranges, build the repeated IDs directly:
+// an ID of `digits` digits made of a block of `k` digits is `block * 10..010..01`.
+// `block_lengths` tells which block lengths count as invalid for a number of digits.
+fn sum_repeated_ids(
+    ranges: &[std::ops::RangeInclusive<u64>],
+    block_lengths: impl Fn(u32) -> Vec<u32>,
+) -> u64 {
+    let mut total = 0;
+
+    for range in ranges {
+        let mut found = HashSet::new();
+        let (start, end) = (*range.start(), *range.end());
+
+        for digits in digits_count(start)..=digits_count(end) {
+            for k in block_lengths(digits) {
+                let mask = 10u64.pow(k);
+                let multiplier = make_n_repeats(1, (digits / k) as usize, mask);
+
+                let lowest_block = (mask / 10).max(start.div_ceil(multiplier));
+                let highest_block = (mask - 1).min(end / multiplier);
+                for block in lowest_block..=highest_block {
+                    found.insert(block * multiplier);
+                }
+            }
+        }
+
+        total += found.iter().sum::<u64>();
+    }
+
+    total
+}
+
+fn digits_count(id: u64) -> u32 {
+    id.checked_ilog10().unwrap_or(0) + 1
+}
+
 fn make_n_repeats(num: u64, repeats: usize, mask: u64) -> u64 {
     let mut result = 0;
     for _ in 0..repeats {
@@ -0,0 +1,22 @@
+use crate::rng::Rng;
+use crate::tasks::task::InputGenerator;
+
+const BANKS_PER_BATTERY: usize = 100;
+
+// `size` batteries, each a line of 100 joltage digits from 1 to 9.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size)
+            .map(|_| {
+                (0..BANKS_PER_BATTERY)
+                    .map(|_| char::from(b'0' + rng.range_inclusive(1..=9) as u8))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
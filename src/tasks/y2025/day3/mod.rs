@@ -1,10 +1,36 @@
-use super::task;
+mod generator;
+
+use crate::tasks::task::{self, Example, InputGenerator, Solver};
+
+const EXAMPLE_INPUT: &str = r#"987654321111111
+811111111111119
+234234234234278
+818181911112111"#;
 
 pub struct Task;
 
 impl task::Task for Task {
     type TaskInput = Input;
 
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            part1: Some("357"),
+            part2: Some("3121910778619"),
+        }]
+    }
+
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        Some(&generator::Generator)
+    }
+
+    fn solvers(&self) -> Vec<Solver<Self>> {
+        vec![
+            Solver::new("greedy", 1, Task::part1_greedy),
+            Solver::new("greedy", 2, Task::part2_greedy),
+        ]
+    }
+
     fn part1(&self, input: Self::TaskInput) -> String {
         let sum: u64 = input
             .batteries
@@ -24,6 +50,18 @@ impl task::Task for Task {
     }
 }
 
+impl Task {
+    fn part1_greedy(&self, input: Input) -> String {
+        let sum: u64 = input.batteries.iter().map(|b| b.greedy_joltage(2)).sum();
+        sum.to_string()
+    }
+
+    fn part2_greedy(&self, input: Input) -> String {
+        let sum: u64 = input.batteries.iter().map(|b| b.greedy_joltage(12)).sum();
+        sum.to_string()
+    }
+}
+
 pub struct Input {
     batteries: Vec<Battery>,
 }
@@ -41,9 +79,9 @@ impl task::TaskInput for Input {
                     .trim()
                     .chars()
                     .map(|num_char| {
-                        num_char.to_string().parse::<u8>().expect(
-                            format!("Expected valid number for bank, got {}", num_char).as_str(),
-                        )
+                        num_char.to_string().parse::<u8>().unwrap_or_else(|_| {
+                            panic!("Expected valid number for bank, got {}", num_char)
+                        })
                     })
                     .collect();
                 Battery { banks }
@@ -54,6 +92,27 @@ impl task::TaskInput for Input {
 }
 
 impl Battery {
+    // Picks every digit as the biggest one that still leaves enough banks for the rest.
+    fn greedy_joltage(&self, n: usize) -> u64 {
+        let mut joltage = 0;
+        let mut start = 0;
+
+        for remaining in (0..n).rev() {
+            let window = &self.banks[start..self.banks.len() - remaining];
+            // max_by_key returns the last maximum, we want the first one
+            let (offset, digit) = window
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|(_, digit)| **digit)
+                .unwrap();
+            joltage = joltage * 10 + *digit as u64;
+            start += offset + 1;
+        }
+
+        joltage
+    }
+
     fn top_joltage(&self) -> u64 {
         self.top_joltage_of_len(2)
     }
@@ -65,19 +124,13 @@ impl Battery {
             // how many banks we have at the tail of the battery. We need at least enough banks to fill the top n digits
             let remaining_banks = self.banks.len() - i;
 
-            let start_index = if remaining_banks > n {
-                0
-            } else {
-                n - remaining_banks
-            };
+            let start_index = n.saturating_sub(remaining_banks);
             let end_index = n.clamp(0, i);
 
             for i in start_index..end_index {
                 if *bank > top_digits[i] {
                     top_digits[i] = *bank;
-                    for j in (i + 1)..n {
-                        top_digits[j] = 0;
-                    }
+                    top_digits[i + 1..].fill(0);
                     break;
                 }
             }
@@ -0,0 +1,22 @@
+use crate::rng::Rng;
+use crate::tasks::task::InputGenerator;
+
+const ROLL_DENSITY: f64 = 0.65;
+
+// A `size` x `size` grid, with roughly the same share of rolls as the real input.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(ROLL_DENSITY) { '@' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,278 @@
+mod generator;
+
+use std::collections::HashSet;
+use std::io;
+
+use crate::automaton::{Automaton, Update};
+use crate::grid::{BitGrid, Frames, Grid, Rgb};
+use crate::point::Point;
+use crate::tasks::task::{Example, InputGenerator, Solver};
+
+const EXAMPLE_INPUT: &str = r#"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@.@
+.@@@@@@@@.
+@.@.@.@@@."#;
+
+pub struct Task {}
+
+impl crate::tasks::task::Task for Task {
+    type TaskInput = crate::grid::Grid<Cell>;
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            part1: Some("16"),
+            part2: Some("70"),
+        }]
+    }
+
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        Some(&generator::Generator)
+    }
+
+    fn solvers(&self) -> Vec<Solver<Self>> {
+        vec![
+            Solver::new("sweep", 2, Task::part2_sweep),
+            Solver::new("worklist", 2, Task::part2_worklist),
+            Solver::new("bitgrid", 1, Task::part1_bitgrid),
+            Solver::new("bitgrid", 2, Task::part2_bitgrid),
+        ]
+    }
+
+    fn part1(&self, input: Self::TaskInput) -> String {
+        input
+            .positions(|&cell| cell == Cell::Roll)
+            .filter(|&point| is_accessible(&input, point))
+            .count()
+            .to_string()
+    }
+
+    fn part2(&self, input: Self::TaskInput) -> String {
+        let removal = Automaton::new(|cell: Cell, neighbours: &[Cell]| {
+            let rolls = neighbours.iter().filter(|&&n| n == Cell::Roll).count();
+            if cell == Cell::Roll && rolls < 4 {
+                Cell::Empty
+            } else {
+                cell
+            }
+        })
+        .update(Update::InPlace);
+
+        let outcome = removal.run(input);
+        outcome.changes.iter().sum::<usize>().to_string()
+    }
+
+    // One frame per removal pass, the rolls about to be removed are red.
+    fn visualise(&self, input: Self::TaskInput, frames: &mut Frames) -> Option<io::Result<()>> {
+        Some(visualise_passes(input, frames))
+    }
+
+    fn trace(
+        &self,
+        input: Self::TaskInput,
+        out: &mut dyn io::Write,
+        colour: bool,
+    ) -> Option<io::Result<()>> {
+        Some(trace_passes(input, out, colour))
+    }
+}
+
+// Removes every roll accessible right now at once, None if there's none
+fn removal_pass(grid: &Grid<Cell>) -> Option<Grid<Cell>> {
+    let next = grid.map(|point, &cell| match cell {
+        Cell::Roll if is_accessible(grid, point) => Cell::Empty,
+        _ => cell,
+    });
+    (next != *grid).then_some(next)
+}
+
+fn visualise_passes(mut grid: Grid<Cell>, frames: &mut Frames) -> io::Result<()> {
+    loop {
+        let next = removal_pass(&grid);
+        let removed: HashSet<Point> = match &next {
+            Some(next) => grid.diff(next).map(|(point, _, _)| point).collect(),
+            None => HashSet::new(),
+        };
+
+        let frame = grid.map(|point, &cell| match cell {
+            Cell::Empty => Rgb(0, 0, 0),
+            Cell::Roll if removed.contains(&point) => Rgb(220, 40, 40),
+            Cell::Roll => Rgb(200, 200, 200),
+        });
+        frames.save_ppm(&frame, 4, |&colour| colour)?;
+
+        match next {
+            Some(next) => grid = next,
+            None => return Ok(()),
+        }
+    }
+}
+
+fn trace_passes(mut grid: Grid<Cell>, out: &mut dyn io::Write, colour: bool) -> io::Result<()> {
+    let mut total_removed = 0;
+    let mut pass = 1;
+    while let Some(next) = removal_pass(&grid) {
+        let removed = grid.diff(&next).count();
+        total_removed += removed;
+        writeln!(out, "Pass {}: removed {} rolls", pass, removed)?;
+        writeln!(out, "{}\n", grid.render_changes(&next, colour))?;
+        grid = next;
+        pass += 1;
+    }
+    writeln!(
+        out,
+        "Removed {} rolls in {} passes",
+        total_removed,
+        pass - 1
+    )
+}
+
+impl Task {
+    // Reference solution sweeping the whole grid, removing rolls as it goes,
+    // until a pass removes nothing.
+    fn part2_sweep(&self, input: Grid<Cell>) -> String {
+        let mut grid = input;
+        let mut total_removed = 0;
+        loop {
+            let mut current_pass_accessible = 0;
+            for point in grid.size().iter_inside() {
+                let cell = match grid.get(point) {
+                    Some(cell) => *cell,
+                    None => continue,
+                };
+                if cell != Cell::Roll {
+                    continue;
+                }
+
+                if is_accessible(&grid, point) {
+                    grid.replace(point, Cell::Empty);
+                    current_pass_accessible += 1;
+                }
+            }
+            if current_pass_accessible == 0 {
+                break;
+            }
+            total_removed += current_pass_accessible;
+        }
+        total_removed.to_string()
+    }
+
+    // Instead of sweeping the whole grid until nothing changes, only re-check
+    // the neighbours of removed rolls.
+    fn part2_worklist(&self, input: crate::grid::Grid<Cell>) -> String {
+        let mut grid = input;
+        let mut total_removed = 0;
+
+        let mut queue: Vec<crate::point::Point> =
+            grid.positions(|&cell| cell == Cell::Roll).collect();
+
+        while let Some(point) = queue.pop() {
+            if grid.value(point) != Some(Cell::Roll) || !is_accessible(&grid, point) {
+                continue;
+            }
+
+            grid.replace(point, Cell::Empty);
+            total_removed += 1;
+            queue.extend(
+                grid.neighbours(point)
+                    .filter(|&(_, &cell)| cell == Cell::Roll)
+                    .map(|(neighbour, _)| neighbour),
+            );
+        }
+
+        total_removed.to_string()
+    }
+
+    fn part1_bitgrid(&self, input: Grid<Cell>) -> String {
+        let rolls = BitGrid::from_grid(&input, |&cell| cell == Cell::Roll);
+        accessible_rolls(&rolls).count_ones().to_string()
+    }
+
+    // Removing a roll never makes another one inaccessible, so removing every
+    // accessible roll at once ends up in the same place as the sequential sweep.
+    fn part2_bitgrid(&self, input: Grid<Cell>) -> String {
+        let mut rolls = BitGrid::from_grid(&input, |&cell| cell == Cell::Roll);
+        let mut total_removed = 0;
+        loop {
+            let accessible = accessible_rolls(&rolls);
+            if accessible.is_empty() {
+                break;
+            }
+            total_removed += accessible.count_ones();
+            rolls ^= &accessible;
+        }
+        total_removed.to_string()
+    }
+}
+
+fn accessible_rolls(rolls: &BitGrid) -> BitGrid {
+    let crowded = rolls.neighbours_at_least(4);
+    rolls & &!&crowded
+}
+
+pub fn is_accessible(grid: &Grid<Cell>, point: Point) -> bool {
+    grid.neighbours(point)
+        .filter(|&(_, &cell)| cell == Cell::Roll)
+        .count()
+        < 4
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    Roll,
+}
+
+impl crate::tasks::task::TaskInput for Cell {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "." => Cell::Empty,
+            "@" => Cell::Roll,
+            _ => panic!("Unexpected cell character: {}", s),
+        }
+    }
+}
+
+impl crate::grid::ToChar for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Roll => '@',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tasks::task::TaskInput;
+
+    #[test]
+    fn test_trace() {
+        let grid: Grid<Cell> = Grid::from_str(EXAMPLE_INPUT);
+        let mut out = Vec::new();
+        trace_passes(grid.clone(), &mut out, false).unwrap();
+        let trace = String::from_utf8(out).unwrap();
+
+        // the first pass removes the rolls counted by part 1
+        assert!(trace.starts_with("Pass 1: removed 16 rolls\n"));
+        assert!(trace.ends_with("Removed 70 rolls in 12 passes\n"));
+        assert!(!trace.contains('\x1b'));
+
+        let mut out = Vec::new();
+        trace_passes(grid, &mut out, true).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains('\x1b'));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let grid: Grid<Cell> = Grid::from_str(EXAMPLE_INPUT);
+        assert_eq!(grid.to_string(), EXAMPLE_INPUT);
+    }
+}
@@ -1,7 +1,29 @@
+use crate::tasks::task::Example;
+
+const EXAMPLE_INPUT: &str = r#"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32"#;
+
 pub struct Task {}
 
-impl super::task::Task for Task {
+impl crate::tasks::task::Task for Task {
     type TaskInput = Input;
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            part1: Some("3"),
+            part2: Some("14"),
+        }]
+    }
     fn part1(&self, input: Self::TaskInput) -> String {
         let mut valid_count = 0;
 
@@ -76,7 +98,7 @@ impl Input {
     }
 }
 
-impl super::task::TaskInput for Input {
+impl crate::tasks::task::TaskInput for Input {
     fn from_str(s: &str) -> Input {
         let (ranges, ids) = s.split_once("\n\n").unwrap();
 
@@ -1,8 +1,26 @@
+use std::convert::Infallible;
+
+use crate::grid::GridParser;
+use crate::tasks::task::Example;
+
+const EXAMPLE_INPUT: &str = r#"123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  "#;
+
 pub struct Task {}
 
-impl super::task::Task for Task {
+impl crate::tasks::task::Task for Task {
     type TaskInput = Input;
 
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            part1: Some("4277556"),
+            part2: Some("3263827"),
+        }]
+    }
+
     fn part1(&self, input: Self::TaskInput) -> String {
         input
             .problems
@@ -51,7 +69,7 @@ pub enum Operator {
     Multiply,
 }
 
-impl super::task::TaskInput for Operator {
+impl crate::tasks::task::TaskInput for Operator {
     fn from_str(s: &str) -> Self {
         match s {
             "+" => Operator::Add,
@@ -95,9 +113,19 @@ impl Problem {
     }
 }
 
-impl super::task::TaskInput for Input {
+impl crate::tasks::task::TaskInput for Input {
     fn from_str(s: &str) -> Self {
-        let lines = s.lines().collect::<Vec<_>>();
+        // editors like to strip the trailing spaces the columns rely on
+        let worksheet = GridParser::new(Ok::<char, Infallible>)
+            .keep_whitespace()
+            .pad_with(' ')
+            .parse(s)
+            .unwrap_or_else(|e| panic!("{}", e));
+        let rows = worksheet
+            .rows()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        let lines = rows.iter().map(String::as_str).collect::<Vec<_>>();
 
         let operators = lines.last().unwrap();
         let digit_lines = lines[..lines.len() - 1].to_vec();
@@ -116,7 +144,7 @@ impl super::task::TaskInput for Input {
                     .collect();
 
                 Problem {
-                    operands: operands,
+                    operands,
                     operator: op,
                 }
             })
@@ -157,7 +185,7 @@ impl Input {
         for (i, &ch) in chars.iter().enumerate().skip(1) {
             if ch != ' ' {
                 let next_operator = Self::operator_from_char(ch)
-                    .expect(format!("expected an operator, got {}", ch).as_str());
+                    .unwrap_or_else(|| panic!("expected an operator, got {}", ch));
                 ranges.push((operator, start_range..i - 1));
                 start_range = i;
                 operator = next_operator;
@@ -179,17 +207,12 @@ impl Input {
 
 #[cfg(test)]
 mod tests {
-    use super::super::task::{Task as _, TaskInput};
     use super::*;
-
-    const TEST_INPUT: &str = r#"123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  "#;
+    use crate::tasks::task::{Task as _, TaskInput};
 
     #[test]
     fn test_part2() {
-        let input = Input::from_str(TEST_INPUT);
+        let input = Input::from_str(EXAMPLE_INPUT);
         let task = Task {};
         let result = task.part2(input);
         assert_eq!(result, "3263827");
@@ -197,7 +220,7 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let input = Input::from_str(TEST_INPUT);
+        let input = Input::from_str(EXAMPLE_INPUT);
         assert_eq!(input.problems.len(), 4);
         assert_eq!(
             input.problems[0].operands,
@@ -244,4 +267,17 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_stripped_trailing_spaces() {
+        let stripped = EXAMPLE_INPUT
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let task = Task {};
+
+        assert_eq!(task.part1(Input::from_str(&stripped)), "4277556");
+        assert_eq!(task.part2(Input::from_str(&stripped)), "3263827");
+    }
 }
@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+
+use crate::grid::{Frames, Grid, Rgb, SparseGrid, ToChar};
+use crate::point::{Neighbourhood, Point};
+use crate::tasks::task::{Example, TaskInput};
+
+const EXAMPLE_INPUT: &str = r#".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+..............."#;
+
+// a splitter sends the beam down to the left and to the right
+const SPLIT: Neighbourhood<'static> =
+    Neighbourhood::Custom(&[Point { x: -1, y: 1 }, Point { x: 1, y: 1 }]);
+
+pub struct Task {}
+
+impl crate::tasks::task::Task for Task {
+    type TaskInput = Field;
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            part1: Some("21"),
+            part2: Some("40"),
+        }]
+    }
+
+    fn part1(&self, input: Self::TaskInput) -> String {
+        let mut field = input;
+
+        for _ in 0..field.grid.size().y {
+            field = field.beam_step();
+        }
+
+        field.splits_count.to_string()
+    }
+
+    fn part2(&self, input: Self::TaskInput) -> String {
+        let field = input;
+
+        let mut timelines: SparseGrid<u64> =
+            field.beam_locations.iter().map(|&loc| (loc, 1)).collect();
+
+        for _ in 0..field.grid.size().y - 1 {
+            let mut new_timelines = SparseGrid::new(0);
+
+            for (loc, count) in timelines.iter() {
+                let possible_locations = field.possible_beam_locations(loc);
+                for new_loc in possible_locations {
+                    *new_timelines.entry(new_loc) += count;
+                }
+            }
+
+            timelines = new_timelines;
+        }
+
+        timelines
+            .iter()
+            .map(|(_, count)| count)
+            .sum::<u64>()
+            .to_string()
+    }
+
+    // One frame per row the beams travel down
+    fn visualise(&self, input: Self::TaskInput, frames: &mut Frames) -> Option<io::Result<()>> {
+        let mut field = input;
+        for _ in 0..field.grid.size().y {
+            if let Err(error) = frames.save_ppm(&field.colours(), 4, |&colour| colour) {
+                return Some(Err(error));
+            }
+            field = field.beam_step();
+        }
+        Some(Ok(()))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Splitter,
+}
+
+pub struct Field {
+    grid: Grid<Cell>,
+    beam_locations: HashSet<Point>,
+    splits_count: u64,
+}
+
+impl TaskInput for Field {
+    fn from_str(input: &str) -> Self {
+        let input_grid: Grid<InputCell> = Grid::from_str(input);
+
+        let mut beam_locations = HashSet::new();
+        let mut grid = Grid::filled(input_grid.size(), Cell::Empty);
+
+        for (p, cell) in input_grid.values() {
+            match cell {
+                InputCell::Cell(c) => {
+                    grid[p] = c;
+                }
+                InputCell::Start => {
+                    beam_locations.insert(p);
+                }
+            }
+        }
+
+        Field {
+            grid,
+            beam_locations,
+            splits_count: 0,
+        }
+    }
+}
+
+// Shows the beams as '|' on top of the field
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let beams = self.beam_locations.iter().copied();
+        f.write_str(&self.grid.render_overlay(beams, '|'))
+    }
+}
+
+impl Field {
+    fn possible_beam_locations(&self, point: Point) -> HashSet<Point> {
+        let bellow = Point {
+            x: point.x,
+            y: point.y + 1,
+        };
+        let mut locations = HashSet::new();
+        match self.grid.get(bellow) {
+            Some(Cell::Empty) => {
+                locations.insert(bellow);
+            }
+            Some(Cell::Splitter) => {
+                locations.extend(self.split(point));
+            }
+            None => {}
+        };
+
+        locations
+    }
+
+    fn split(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.grid.neighbours_in(point, &SPLIT).map(|(p, _)| p)
+    }
+
+    fn colours(&self) -> Grid<Rgb> {
+        self.grid.map(|point, cell| match cell {
+            _ if self.beam_locations.contains(&point) => Rgb(255, 210, 60),
+            Cell::Empty => Rgb(10, 10, 30),
+            Cell::Splitter => Rgb(150, 150, 170),
+        })
+    }
+
+    fn beam_step(&self) -> Self {
+        let mut new_beam_locations = HashSet::new();
+        let mut new_splits_count = self.splits_count;
+
+        for &loc in &self.beam_locations {
+            let below = Point {
+                x: loc.x,
+                y: loc.y + 1,
+            };
+
+            match self.grid.get(below) {
+                Some(Cell::Empty) => {
+                    new_beam_locations.insert(below);
+                }
+                Some(Cell::Splitter) => {
+                    new_splits_count += 1;
+                    new_beam_locations.extend(self.split(loc));
+                }
+                None => {}
+            }
+        }
+
+        Field {
+            grid: self.grid.clone(),
+            beam_locations: new_beam_locations,
+            splits_count: new_splits_count,
+        }
+    }
+}
+
+impl ToChar for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Splitter => '^',
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum InputCell {
+    Cell(Cell),
+    Start,
+}
+
+impl TaskInput for InputCell {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "." => Self::Cell(Cell::Empty),
+            "^" => Self::Cell(Cell::Splitter),
+            "S" => Self::Start,
+            _ => panic!("Invalid input value: {}", s),
+        }
+    }
+}
+
+impl ToChar for InputCell {
+    fn to_char(&self) -> char {
+        match self {
+            InputCell::Cell(cell) => cell.to_char(),
+            InputCell::Start => 'S',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tasks::task::Task as _;
+
+    #[test]
+    fn test_part1() {
+        let task = Task {};
+        let input = Field::from_str(EXAMPLE_INPUT);
+        let result = task.part1(input);
+        assert_eq!(result, "21");
+    }
+
+    #[test]
+    fn test_part2() {
+        let task = Task {};
+        let input = Field::from_str(EXAMPLE_INPUT);
+        let result = task.part2(input);
+        assert_eq!(result, "40");
+    }
+
+    #[test]
+    fn test_display() {
+        let input_grid: Grid<InputCell> = Grid::from_str(EXAMPLE_INPUT);
+        assert_eq!(input_grid.to_string(), EXAMPLE_INPUT);
+
+        let field = Field::from_str(EXAMPLE_INPUT).beam_step().beam_step();
+        let rendered = field.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "...............");
+        assert_eq!(lines[1], "...............");
+        assert_eq!(lines[2], "......|^|......");
+    }
+
+    #[test]
+    fn test_colours() {
+        let field = Field::from_str(EXAMPLE_INPUT).beam_step();
+        let colours = field.colours();
+        assert_eq!(colours[Point::new(7, 1)], Rgb(255, 210, 60));
+        assert_eq!(colours[Point::new(7, 2)], Rgb(150, 150, 170));
+        assert_eq!(colours[Point::new(0, 0)], Rgb(10, 10, 30));
+    }
+}
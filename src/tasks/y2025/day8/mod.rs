@@ -1,10 +1,43 @@
 use std::collections::HashSet;
 
+use crate::point::Point3;
+use crate::tasks::task::Example;
+
+const EXAMPLE_INPUT: &str = r#"162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689"#;
+
 pub struct Task {}
 
-impl super::task::Task for Task {
+impl crate::tasks::task::Task for Task {
     type TaskInput = Points;
 
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            input: EXAMPLE_INPUT,
+            // part 1 always joins the 1000 closest pairs, the example is about 10
+            part1: None,
+            part2: Some("25272"),
+        }]
+    }
+
     fn part1(&self, input: Self::TaskInput) -> String {
         let shortest_distances = Task::get_n_shortest_distances(input, 1000);
 
@@ -31,7 +64,7 @@ impl super::task::Task for Task {
 
             if grouper.groups.len() == 1 && grouper.groups[0].len() == input.points.len() {
                 // p1 to p2 is the last match
-                return (input.points[p1].x * input.points[p2].x).to_string();
+                return (input.points[p1].x() * input.points[p2].x()).to_string();
             }
         }
 
@@ -99,12 +132,13 @@ impl PointGroupper {
 
     fn biggest_groups(&self, n: usize) -> Vec<&HashSet<usize>> {
         let mut groups: Vec<&HashSet<usize>> = self.groups.iter().collect();
-        groups.sort_by(|a, b| b.len().cmp(&a.len()));
+        groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
         groups.truncate(n);
         groups
     }
 }
 
+#[cfg(test)]
 fn group_points(points: Vec<(usize, usize)>) -> Vec<HashSet<usize>> {
     let mut groups: Vec<HashSet<usize>> = vec![];
 
@@ -138,45 +172,23 @@ fn group_points(points: Vec<(usize, usize)>) -> Vec<HashSet<usize>> {
     groups
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: u64,
-    y: u64,
-    z: u64,
-}
-
-impl Point {
-    fn new(x: u64, y: u64, z: u64) -> Self {
-        Point { x, y, z }
-    }
-}
-
 pub struct Points {
-    points: Vec<Point>,
+    points: Vec<Point3>,
 }
 
-impl super::task::TaskInput for Points {
+impl crate::tasks::task::TaskInput for Points {
     fn from_str(input: &str) -> Self {
         let points = input
             .lines()
             .map(|line| {
-                let coords: Vec<u64> = line.split(',').map(|num| num.parse().unwrap()).collect();
-                Point::new(coords[0], coords[1], coords[2])
+                let coords: Vec<i64> = line.split(',').map(|num| num.parse().unwrap()).collect();
+                Point3::new([coords[0], coords[1], coords[2]])
             })
             .collect();
         Points { points }
     }
 }
 
-impl Point {
-    fn distance_squared(&self, other: &Point) -> u64 {
-        let dx = self.x as i64 - other.x as i64;
-        let dy = self.y as i64 - other.y as i64;
-        let dz = self.z as i64 - other.z as i64;
-        (dx * dx + dy * dy + dz * dz) as u64
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,26 +197,26 @@ mod tests {
     fn test_distances() {
         let points = Points {
             points: vec![
-                Point::new(162, 817, 812), // 0
-                Point::new(57, 618, 57),   // 1
-                Point::new(906, 360, 560), // 2
-                Point::new(592, 479, 940), // 3
-                Point::new(352, 342, 300), // 4
-                Point::new(466, 668, 158), // 5
-                Point::new(542, 29, 236),  // 6
-                Point::new(431, 825, 988), // 7
-                Point::new(739, 650, 466), // 8
-                Point::new(52, 470, 668),  // 9
-                Point::new(216, 146, 977), // 10
-                Point::new(819, 987, 18),  // 11
-                Point::new(117, 168, 530), // 12
-                Point::new(805, 96, 715),  // 13
-                Point::new(346, 949, 466), // 14
-                Point::new(970, 615, 88),  // 15
-                Point::new(941, 993, 340), // 16
-                Point::new(862, 61, 35),   // 17
-                Point::new(984, 92, 344),  // 18
-                Point::new(425, 690, 689), // 19
+                Point3::new([162, 817, 812]), // 0
+                Point3::new([57, 618, 57]),   // 1
+                Point3::new([906, 360, 560]), // 2
+                Point3::new([592, 479, 940]), // 3
+                Point3::new([352, 342, 300]), // 4
+                Point3::new([466, 668, 158]), // 5
+                Point3::new([542, 29, 236]),  // 6
+                Point3::new([431, 825, 988]), // 7
+                Point3::new([739, 650, 466]), // 8
+                Point3::new([52, 470, 668]),  // 9
+                Point3::new([216, 146, 977]), // 10
+                Point3::new([819, 987, 18]),  // 11
+                Point3::new([117, 168, 530]), // 12
+                Point3::new([805, 96, 715]),  // 13
+                Point3::new([346, 949, 466]), // 14
+                Point3::new([970, 615, 88]),  // 15
+                Point3::new([941, 993, 340]), // 16
+                Point3::new([862, 61, 35]),   // 17
+                Point3::new([984, 92, 344]),  // 18
+                Point3::new([425, 690, 689]), // 19
             ],
         };
         let top_distances = Task::get_n_shortest_distances(points, 10);
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use super::Tiles;
+use crate::grid::{Grid, GridLike};
+use crate::point::Point;
+
+// Reference part 2 solver. Coordinates are compressed so that every distinct x and y
+// of a red tile gets its own column/row, with a gap column/row between neighbours
+// that have tiles between them.
+// The outside of the polygon is then flood filled on the small compressed grid and
+// a rectangle is valid when it doesn't contain any outside cell.
+pub struct CompressedSolver {
+    tiles: Vec<Point>,
+    xs: HashMap<i64, i64>,
+    ys: HashMap<i64, i64>,
+    // outside[y][x] is the amount of outside cells in the compressed grid above and left of (x, y)
+    outside: Vec<Vec<i64>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Unknown,
+    Border,
+    Outside,
+}
+
+impl CompressedSolver {
+    pub fn new(tiles: &Tiles) -> Self {
+        let xs = Self::compress(tiles.tiles.iter().map(|p| p.x));
+        let ys = Self::compress(tiles.tiles.iter().map(|p| p.y));
+        let size = Point::new(
+            xs.values().max().unwrap() + 2,
+            ys.values().max().unwrap() + 2,
+        );
+
+        let mut grid = Grid::filled(size, Cell::Unknown);
+
+        for i in 0..tiles.tiles.len() {
+            let start = tiles.tiles[i];
+            let end = tiles.tiles[(i + 1) % tiles.tiles.len()];
+            let start = Point::new(xs[&start.x], ys[&start.y]);
+            let end = Point::new(xs[&end.x], ys[&end.y]);
+            for p in start.line(&end) {
+                grid.replace(p, Cell::Border);
+            }
+        }
+
+        for point in grid.flood_fill(Point::new(0, 0), |_, cell| cell == Cell::Unknown) {
+            grid[point] = Cell::Outside;
+        }
+
+        let mut outside = vec![vec![0; size.x as usize + 1]; size.y as usize + 1];
+        for (p, cell) in grid.values() {
+            let (x, y) = (p.x as usize, p.y as usize);
+            outside[y + 1][x + 1] = outside[y][x + 1] + outside[y + 1][x] - outside[y][x]
+                + (cell == Cell::Outside) as i64;
+        }
+
+        CompressedSolver {
+            tiles: tiles.tiles.clone(),
+            xs,
+            ys,
+            outside,
+        }
+    }
+
+    fn compress(values: impl Iterator<Item = i64>) -> HashMap<i64, i64> {
+        let mut values: Vec<i64> = values.collect();
+        values.sort();
+        values.dedup();
+
+        let mut compressed = HashMap::new();
+        let mut index = 1;
+        for (i, &v) in values.iter().enumerate() {
+            // adjacent values have no tiles between them, a gap there would be
+            // an outside cell squeezed between two borders
+            if i > 0 {
+                index += if v - values[i - 1] > 1 { 2 } else { 1 };
+            }
+            compressed.insert(v, index);
+        }
+        compressed
+    }
+
+    pub fn solve(&self) -> i64 {
+        let mut max_area = 0;
+
+        for (i, p1) in self.tiles.iter().enumerate() {
+            for p2 in self.tiles.iter().skip(i + 1) {
+                let (x1, x2) = (
+                    self.xs[&p1.x].min(self.xs[&p2.x]),
+                    self.xs[&p1.x].max(self.xs[&p2.x]),
+                );
+                let (y1, y2) = (
+                    self.ys[&p1.y].min(self.ys[&p2.y]),
+                    self.ys[&p1.y].max(self.ys[&p2.y]),
+                );
+
+                if self.outside_cells(x1 as usize, y1 as usize, x2 as usize, y2 as usize) == 0 {
+                    max_area = max_area.max(Tiles::area((*p1, *p2)));
+                }
+            }
+        }
+
+        max_area
+    }
+
+    fn outside_cells(&self, x1: usize, y1: usize, x2: usize, y2: usize) -> i64 {
+        self.outside[y2 + 1][x2 + 1] - self.outside[y1][x2 + 1] - self.outside[y2 + 1][x1]
+            + self.outside[y1][x1]
+    }
+}
@@ -0,0 +1,58 @@
+use crate::rng::Rng;
+use crate::tasks::task::InputGenerator;
+
+// distance between neighbouring columns and vertical resolution of the staircases
+const SCALE: u64 = 20;
+
+// A simple rectilinear polygon with about `size` red tiles (always a multiple of 4).
+//
+// The polygon is a row of columns with random top and bottom heights. Neighbouring
+// columns always overlap vertically, so walking the top staircase left to right and
+// the bottom one back never crosses itself.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let columns = (size / 4).max(1);
+        let max_height = SCALE * columns as u64;
+
+        let mut xs = vec![rng.range_inclusive(0..=SCALE)];
+        for _ in 0..columns {
+            let last = xs[xs.len() - 1];
+            xs.push(last + rng.range_inclusive(1..=SCALE));
+        }
+
+        let mut spans: Vec<(u64, u64)> = vec![];
+        while spans.len() < columns {
+            // columns at least two tall always leave room for an overlapping neighbour
+            let bottom = rng.range(0..max_height - 1);
+            let top = rng.range_inclusive(bottom + 2..=max_height);
+
+            if let Some(&(prev_bottom, prev_top)) = spans.last()
+                && (bottom == prev_bottom
+                    || top == prev_top
+                    || bottom.max(prev_bottom) >= top.min(prev_top))
+            {
+                continue;
+            }
+            spans.push((bottom, top));
+        }
+
+        let mut tiles = vec![];
+        for (i, &(_, top)) in spans.iter().enumerate() {
+            tiles.push((xs[i], top));
+            tiles.push((xs[i + 1], top));
+        }
+        for (i, &(bottom, _)) in spans.iter().enumerate().rev() {
+            tiles.push((xs[i + 1], bottom));
+            tiles.push((xs[i], bottom));
+        }
+
+        tiles
+            .iter()
+            .map(|(x, y)| format!("{},{}", x, y))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
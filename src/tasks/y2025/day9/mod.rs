@@ -1,17 +1,94 @@
+mod compressed;
+mod generator;
+
 use std::collections::HashMap;
-use std::collections::HashSet;
 
 use crate::point::Point;
+use crate::tasks::task::{Example, InputGenerator, Solver};
+
+const EXAMPLE_INPUT: &str = r#"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3"#;
+
+// Polygons the two part 2 solvers used to disagree on. In the first one the default
+// solver went around the U turns at 26-27 as if they were S bends, in the second one
+// the compressed solver saw an outside gap between the adjacent columns 17 and 18.
+const U_TURN_INPUT: &str = r#"6,42
+12,42
+12,38
+26,38
+26,49
+27,49
+27,48
+34,48
+34,40
+27,40
+27,36
+26,36
+26,35
+12,35
+12,34
+6,34"#;
+
+const ADJACENT_COLUMNS_INPUT: &str = r#"1,67
+4,67
+4,51
+17,51
+17,13
+18,13
+18,47
+31,47
+31,12
+18,12
+18,5
+17,5
+17,6
+4,6
+4,47
+1,47"#;
 
 pub struct Task {}
 
-impl super::task::Task for Task {
+impl crate::tasks::task::Task for Task {
     type TaskInput = Tiles;
 
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                input: EXAMPLE_INPUT,
+                part1: Some("50"),
+                part2: Some("24"),
+            },
+            Example {
+                input: U_TURN_INPUT,
+                part1: Some("435"),
+                part2: Some("72"),
+            },
+            Example {
+                input: ADJACENT_COLUMNS_INPUT,
+                part1: Some("1736"),
+                part2: Some("1008"),
+            },
+        ]
+    }
+
+    fn generator(&self) -> Option<&'static dyn InputGenerator> {
+        Some(&generator::Generator)
+    }
+
+    fn solvers(&self) -> Vec<Solver<Self>> {
+        vec![Solver::new("compressed", 2, Task::part2_compressed)]
+    }
+
     fn part1(&self, input: Self::TaskInput) -> String {
         input
             .rectagles()
-            .map(|rect| Tiles::area(rect))
+            .map(Tiles::area)
             .max()
             .unwrap()
             .to_string()
@@ -23,11 +100,19 @@ impl super::task::Task for Task {
     }
 }
 
+impl Task {
+    fn part2_compressed(&self, input: Tiles) -> String {
+        compressed::CompressedSolver::new(&input)
+            .solve()
+            .to_string()
+    }
+}
+
 pub struct Tiles {
     tiles: Vec<Point>,
 }
 
-impl super::task::TaskInput for Tiles {
+impl crate::tasks::task::TaskInput for Tiles {
     fn from_str(s: &str) -> Self {
         let tiles = s
             .lines()
@@ -81,7 +166,9 @@ enum BorderType {
     // going through the vertice doesn't change insideness
     // you start sliding across the edge
     // insideness changes every TWO vertices or ONE edge
-    Red,   //Red borders are vertices of the figure
+    //Red borders are vertices of the figure, with the side (-1 or 1) the edge
+    // through them goes to, across the scanning line
+    Red(i64),
     Green, //Green borders are intersections with edges of the figure
 }
 
@@ -96,7 +183,8 @@ impl Part2Solver {
             if start.x == end.x {
                 for p in start.line(&end) {
                     let border_type = if p == start || p == end {
-                        BorderType::Red
+                        let other = if p == start { end } else { start };
+                        BorderType::Red((other.y - p.y).signum())
                     } else {
                         BorderType::Green
                     };
@@ -111,7 +199,8 @@ impl Part2Solver {
             } else if start.y == end.y {
                 for p in start.line(&end) {
                     let border_type = if p == start || p == end {
-                        BorderType::Red
+                        let other = if p == start { end } else { start };
+                        BorderType::Red((other.x - p.x).signum())
                     } else {
                         BorderType::Green
                     };
@@ -156,18 +245,15 @@ impl Part2Solver {
                     println!("Checking rectangle {}/{}", counter, total);
                 }
 
-                let (a, b, c, d) = (
-                    p1.clone(),
-                    Point::new(p1.x, p2.y),
-                    p2.clone(),
-                    Point::new(p2.x, p1.y),
-                );
+                let (a, b, c, d) = (*p1, Point::new(p1.x, p2.y), *p2, Point::new(p2.x, p1.y));
 
+                // every corner once
                 let mut rectangle_edge = a
                     .line(&b)
-                    .chain(b.line(&c))
-                    .chain(c.line(&d))
-                    .chain(d.line(&a));
+                    .without_end()
+                    .chain(b.line(&c).without_end())
+                    .chain(c.line(&d).without_end())
+                    .chain(d.line(&a).without_end());
 
                 // it's enought to ensure that line edges are not colourless
                 // No need to check insides
@@ -188,7 +274,7 @@ impl Part2Solver {
         match self.horizontal_borders.get(&point.x) {
             Some(borders) => {
                 // dbg!("horizontal", &borders, point);
-                if !Self::check_border(&borders, point.y) {
+                if !Self::check_border(borders, point.y) {
                     return false;
                 }
             }
@@ -200,7 +286,7 @@ impl Part2Solver {
         match self.vertical_borders.get(&point.y) {
             Some(borders) => {
                 // dbg!("vertical", &borders, point);
-                if !Self::check_border(&borders, point.x) {
+                if !Self::check_border(borders, point.x) {
                     return false;
                 }
             }
@@ -209,7 +295,7 @@ impl Part2Solver {
             }
         };
 
-        return true;
+        true
     }
 
     fn check_border(borders: &Vec<Border>, coordinate: i64) -> bool {
@@ -231,38 +317,55 @@ impl Part2Solver {
                 if coordinate == border.coordinate {
                     return true;
                 }
-                match (&state, &border.border_type) {
-                    (ScanningState::Outside, BorderType::Red) => {
-                        state = ScanningState::AlongTheEdge
+                state = match (state, border.border_type) {
+                    (ScanningState::Outside, BorderType::Red(side)) => {
+                        ScanningState::AlongTheEdge {
+                            inside_before: false,
+                            side,
+                        }
                     }
-                    (ScanningState::Outside, BorderType::Green) => state = ScanningState::Inside,
-                    (ScanningState::AlongTheEdge, BorderType::Red) => {
-                        //we actually have no idea here, need to figure out how to combine the result of two scans
-                        // in such a way that we'll know whether or not we're inside at this point
-                        state = ScanningState::Inside
+                    (ScanningState::Outside, BorderType::Green) => ScanningState::Inside,
+                    (
+                        ScanningState::AlongTheEdge {
+                            inside_before,
+                            side,
+                        },
+                        BorderType::Red(end_side),
+                    ) => {
+                        // a U turn (both edges on the same side) leaves us where we were,
+                        // an S bend crosses the figure's border
+                        if (side == end_side) == inside_before {
+                            ScanningState::Inside
+                        } else {
+                            ScanningState::Outside
+                        }
                     }
-                    (ScanningState::AlongTheEdge, BorderType::Green) => {
+                    (ScanningState::AlongTheEdge { .. }, BorderType::Green) => {
                         unreachable!(
                             "Edge should end with red tile. Got green: line: {:?}, point: {:?}",
                             borders, coordinate
                         )
                     }
-                    (ScanningState::Inside, BorderType::Red) => state = ScanningState::AlongTheEdge,
-                    (ScanningState::Inside, BorderType::Green) => state = ScanningState::Outside,
+                    (ScanningState::Inside, BorderType::Red(side)) => ScanningState::AlongTheEdge {
+                        inside_before: true,
+                        side,
+                    },
+                    (ScanningState::Inside, BorderType::Green) => ScanningState::Outside,
                 }
             }
 
             match state {
-                ScanningState::Inside | ScanningState::AlongTheEdge => true,
+                ScanningState::Inside | ScanningState::AlongTheEdge { .. } => true,
                 ScanningState::Outside => false,
             }
         }
     }
 }
 
+#[derive(Clone, Copy)]
 enum ScanningState {
     Outside,
-    AlongTheEdge,
+    AlongTheEdge { inside_before: bool, side: i64 },
     Inside,
 }
 
@@ -314,17 +417,12 @@ mod tests {
 
         let solver = Part2Solver::new(&tiles);
 
-        assert_eq!(solver.is_point_inside(&Point::new(7, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(3, 5)), true);
+        assert!(solver.is_point_inside(&Point::new(7, 4)));
+        assert!(solver.is_point_inside(&Point::new(3, 5)));
 
         {
             let (p1, p2) = (Point::new(2, 3), Point::new(9, 5));
-            let (a, b, c, d) = (
-                p1.clone(),
-                Point::new(p1.x, p2.y),
-                p2.clone(),
-                Point::new(p2.x, p1.y),
-            );
+            let (a, b, c, d) = (p1, Point::new(p1.x, p2.y), p2, Point::new(p2.x, p1.y));
 
             let rectangle_edge = a
                 .line(&b)
@@ -333,7 +431,7 @@ mod tests {
                 .chain(d.line(&a));
 
             for p in rectangle_edge {
-                assert_eq!(solver.is_point_inside(&p), true, "Failed at point {:?}", p);
+                assert!(solver.is_point_inside(&p), "Failed at point {:?}", p);
             }
         }
 
@@ -342,49 +440,49 @@ mod tests {
 
         assert_eq!(solver.horizontal_borders.len(), 10);
 
-        assert_eq!(solver.is_point_inside(&Point::new(0, 0)), false);
-        assert_eq!(solver.is_point_inside(&Point::new(11, 8)), false);
+        assert!(!solver.is_point_inside(&Point::new(0, 0)));
+        assert!(!solver.is_point_inside(&Point::new(11, 8)));
 
         for p in tiles.tiles.iter() {
-            assert_eq!(solver.is_point_inside(p), true);
+            assert!(solver.is_point_inside(p));
         }
 
         for x in 2..=5 {
-            assert_eq!(solver.is_point_inside(&Point::new(x, 0)), false);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 1)), false);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 2)), false);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 3)), true);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 4)), true);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 5)), true);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 6)), false);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 7)), false);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 8)), false);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 9)), false);
-            assert_eq!(solver.is_point_inside(&Point::new(x, 10)), false);
+            assert!(!solver.is_point_inside(&Point::new(x, 0)));
+            assert!(!solver.is_point_inside(&Point::new(x, 1)));
+            assert!(!solver.is_point_inside(&Point::new(x, 2)));
+            assert!(solver.is_point_inside(&Point::new(x, 3)));
+            assert!(solver.is_point_inside(&Point::new(x, 4)));
+            assert!(solver.is_point_inside(&Point::new(x, 5)));
+            assert!(!solver.is_point_inside(&Point::new(x, 6)));
+            assert!(!solver.is_point_inside(&Point::new(x, 7)));
+            assert!(!solver.is_point_inside(&Point::new(x, 8)));
+            assert!(!solver.is_point_inside(&Point::new(x, 9)));
+            assert!(!solver.is_point_inside(&Point::new(x, 10)));
         }
 
-        assert_eq!(solver.is_point_inside(&Point::new(7, 0)), false);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 1)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 2)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 3)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 5)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 6)), false);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 7)), false);
-
-        assert_eq!(solver.is_point_inside(&Point::new(0, 4)), false);
-        assert_eq!(solver.is_point_inside(&Point::new(1, 4)), false);
-        assert_eq!(solver.is_point_inside(&Point::new(2, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(3, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(4, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(5, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(6, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(7, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(8, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(9, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(10, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(11, 4)), true);
-        assert_eq!(solver.is_point_inside(&Point::new(12, 4)), false);
-        assert_eq!(solver.is_point_inside(&Point::new(13, 4)), false);
+        assert!(!solver.is_point_inside(&Point::new(7, 0)));
+        assert!(solver.is_point_inside(&Point::new(7, 1)));
+        assert!(solver.is_point_inside(&Point::new(7, 2)));
+        assert!(solver.is_point_inside(&Point::new(7, 3)));
+        assert!(solver.is_point_inside(&Point::new(7, 4)));
+        assert!(solver.is_point_inside(&Point::new(7, 5)));
+        assert!(!solver.is_point_inside(&Point::new(7, 6)));
+        assert!(!solver.is_point_inside(&Point::new(7, 7)));
+
+        assert!(!solver.is_point_inside(&Point::new(0, 4)));
+        assert!(!solver.is_point_inside(&Point::new(1, 4)));
+        assert!(solver.is_point_inside(&Point::new(2, 4)));
+        assert!(solver.is_point_inside(&Point::new(3, 4)));
+        assert!(solver.is_point_inside(&Point::new(4, 4)));
+        assert!(solver.is_point_inside(&Point::new(5, 4)));
+        assert!(solver.is_point_inside(&Point::new(6, 4)));
+        assert!(solver.is_point_inside(&Point::new(7, 4)));
+        assert!(solver.is_point_inside(&Point::new(8, 4)));
+        assert!(solver.is_point_inside(&Point::new(9, 4)));
+        assert!(solver.is_point_inside(&Point::new(10, 4)));
+        assert!(solver.is_point_inside(&Point::new(11, 4)));
+        assert!(!solver.is_point_inside(&Point::new(12, 4)));
+        assert!(!solver.is_point_inside(&Point::new(13, 4)));
     }
 }
@@ -0,0 +1,28 @@
+use super::Registration;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub mod day10;
+
+pub const YEAR: u32 = 2025;
+
+pub const TASKS: &[Registration] = &[
+    Registration::new(YEAR, 1, &day1::Task {}),
+    Registration::new(YEAR, 2, &day2::Task),
+    Registration::new(YEAR, 3, &day3::Task),
+    Registration::new(YEAR, 4, &day4::Task {}),
+    Registration::new(YEAR, 5, &day5::Task {}),
+    Registration::new(YEAR, 6, &day6::Task {}),
+    Registration::new(YEAR, 7, &day7::Task {}),
+    Registration::new(YEAR, 8, &day8::Task {}),
+    Registration::new(YEAR, 9, &day9::Task {}),
+    Registration::new(YEAR, 10, &day10::Task {}),
+];
@@ -0,0 +1,51 @@
+use aoc2025::registry;
+
+#[test]
+fn test_generators_are_deterministic() {
+    for registration in registry() {
+        let Some(generator) = registration.task.generator() else {
+            continue;
+        };
+
+        assert_eq!(
+            generator.generate(20, 7),
+            generator.generate(20, 7),
+            "{} day {}",
+            registration.year,
+            registration.day
+        );
+        assert_ne!(
+            generator.generate(20, 7),
+            generator.generate(20, 8),
+            "{} day {}",
+            registration.year,
+            registration.day
+        );
+    }
+}
+
+#[test]
+fn test_generated_inputs_are_solvable() {
+    for registration in registry() {
+        let Some(generator) = registration.task.generator() else {
+            continue;
+        };
+        // only run the parts that are solved for the examples
+        let solved = |part| {
+            registration
+                .task
+                .examples()
+                .iter()
+                .all(|e| e.expected(part).is_some())
+        };
+
+        for seed in 0..5 {
+            let input = generator.generate(12, seed);
+            for part in [1, 2] {
+                if solved(part) {
+                    assert!(!registration.task.run_str(&input, part).is_empty());
+                }
+            }
+        }
+    }
+}
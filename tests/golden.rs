@@ -0,0 +1,28 @@
+use aoc2025::tasks::golden;
+use aoc2025::{golden_tests, registry};
+
+golden_tests! {
+    y2025 = 2025 {
+        day1 = 1,
+        day2 = 2,
+        day3 = 3,
+        day4 = 4,
+        day5 = 5,
+        day6 = 6,
+        day7 = 7,
+        day8 = 8,
+        day9 = 9,
+        day10 = 10,
+    }
+}
+
+#[test]
+fn test_every_registered_day_has_examples() {
+    assert_eq!(golden::days_without_examples(), vec![]);
+}
+
+#[test]
+fn test_every_registered_day_has_golden_tests() {
+    let registered: Vec<(u32, u32)> = registry().map(|r| (r.year, r.day)).collect();
+    assert_eq!(GOLDEN_DAYS, registered.as_slice());
+}
@@ -0,0 +1,46 @@
+use aoc2025::{Direction, Grid, Point, TaskInput};
+
+#[test]
+fn test_from_str() {
+    let grid: Grid<Direction> = Grid::from_str("^^>\n<vv\n");
+
+    assert_eq!(grid.size(), Point::new(3, 2));
+    assert_eq!(grid.get(Point::new(2, 0)), Some(&Direction::East));
+    assert_eq!(grid.get(Point::new(1, 1)), Some(&Direction::South));
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, -1)), None);
+}
+
+#[test]
+fn test_replace_and_iter() {
+    let mut grid = Grid::new(vec![vec![0, 0], vec![0, 0]]);
+    grid.replace(Point::new(1, 1), 7);
+    grid.replace(Point::new(5, 5), 9);
+
+    let values: Vec<(Point, &i32)> = grid.iter().filter(|(_, v)| **v != 0).collect();
+    assert_eq!(values, vec![(Point::new(1, 1), &7)]);
+    assert_eq!(grid.values().map(|(_, v)| v).sum::<i32>(), 7);
+}
+
+#[test]
+fn test_non_copy_cells() {
+    let mut grid = Grid::new(vec![
+        vec![vec!["a"], vec![]],
+        vec![vec!["b", "c"], vec!["d"]],
+    ]);
+
+    assert_eq!(grid.get(Point::new(0, 1)), Some(&vec!["b", "c"]));
+    for (_, cell) in grid.iter_mut() {
+        cell.push("z");
+    }
+    grid[Point::new(1, 0)].push("y");
+
+    let lengths = grid.map(|_, cell| cell.len());
+    assert_eq!(lengths, Grid::new(vec![vec![2, 2], vec![3, 2]]));
+    assert_eq!(grid.count(|cell| cell.len() == 2), 3);
+    assert_eq!(
+        grid.positions(|cell| cell.contains(&"y"))
+            .collect::<Vec<_>>(),
+        vec![Point::new(1, 0)]
+    );
+}
@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+
+use aoc2025::{Neighbourhood, Point, Point2, Point3, PointN};
+
+#[test]
+fn test_neighbours() {
+    let point = Point::new(0, 0);
+
+    assert_eq!(point.neighbours().count(), 8);
+    assert_eq!(point.direct_neighbours().count(), 4);
+    assert!(point.direct_neighbours().all(|n| (n.x + n.y).abs() == 1));
+}
+
+#[test]
+fn test_iter_inside() {
+    let points: Vec<Point> = Point::new(2, 2).iter_inside().collect();
+    assert_eq!(
+        points,
+        vec![
+            Point::new(0, 0),
+            Point::new(1, 0),
+            Point::new(0, 1),
+            Point::new(1, 1)
+        ]
+    );
+}
+
+#[test]
+fn test_line() {
+    let line: Vec<Point> = Point::new(3, 1).line(&Point::new(1, 1)).collect();
+    assert_eq!(line.len(), 3);
+    assert_eq!(Point::new(1, 1) + Point::new(1, 0), line[1]);
+    assert_eq!(
+        line,
+        vec![Point::new(3, 1), Point::new(2, 1), Point::new(1, 1)]
+    );
+
+    let vertical: Vec<Point> = Point::new(0, 0).line(&Point::new(0, 2)).collect();
+    assert_eq!(
+        vertical,
+        vec![Point::new(0, 0), Point::new(0, 1), Point::new(0, 2)]
+    );
+
+    let single: Vec<Point> = Point::new(4, 4).line(&Point::new(4, 4)).collect();
+    assert_eq!(single, vec![Point::new(4, 4)]);
+}
+
+#[test]
+fn test_diagonal_lines() {
+    let diagonal: Vec<Point> = Point::new(0, 3).line(&Point::new(3, 0)).collect();
+    assert_eq!(
+        diagonal,
+        vec![
+            Point::new(0, 3),
+            Point::new(1, 2),
+            Point::new(2, 1),
+            Point::new(3, 0)
+        ]
+    );
+
+    // one point per column for shallow slopes, one per row for steep ones
+    let shallow: Vec<Point> = Point::new(0, 0).line(&Point::new(4, 2)).collect();
+    assert_eq!(shallow.len(), 5);
+    assert_eq!(shallow.last(), Some(&Point::new(4, 2)));
+    assert!(shallow.windows(2).all(|w| w[0].chebyshev(&w[1]) == 1));
+
+    let steep: Vec<Point> = Point::new(2, 7).line(&Point::new(-1, 0)).collect();
+    assert_eq!(steep.len(), 8);
+    assert_eq!(steep.last(), Some(&Point::new(-1, 0)));
+    assert!(steep.windows(2).all(|w| w[1].y == w[0].y - 1));
+}
+
+#[test]
+fn test_half_open_lines() {
+    let (a, b) = (Point::new(0, 0), Point::new(3, 0));
+
+    let line = a.line(&b).without_end();
+    assert_eq!(line.remaining(), 3);
+    assert_eq!(line.size_hint(), (3, Some(3)));
+    assert_eq!(line.last(), Some(Point::new(2, 0)));
+    assert_eq!(a.line(&b).without_start().next(), Some(Point::new(1, 0)));
+    assert_eq!(a.line(&b).without_start().without_end().count(), 2);
+    assert_eq!(a.line(&a).without_end().count(), 0);
+}
+
+#[test]
+fn test_line_is_lazy() {
+    let far = Point::new(i64::MAX / 4, i64::MAX / 4);
+    let start: Vec<Point> = Point::new(0, 0).line(&far).take(2).collect();
+    assert_eq!(start, vec![Point::new(0, 0), Point::new(1, 1)]);
+}
+
+#[test]
+fn test_lines_at_the_extremes() {
+    let (min, max) = (Point::new(i64::MIN, 0), Point::new(i64::MAX, 0));
+    let across = min.line(&max);
+    assert_eq!(across.remaining(), 1 << 64);
+    assert_eq!(across.size_hint(), (usize::MAX, None));
+    assert_eq!(across.clone().without_start().remaining(), u64::MAX as u128);
+    let start: Vec<Point> = across.take(2).collect();
+    assert_eq!(start, vec![min, Point::new(i64::MIN + 1, 0)]);
+
+    let back: Vec<Point> = max.line(&min).take(2).collect();
+    assert_eq!(back, vec![max, Point::new(i64::MAX - 1, 0)]);
+
+    // dx and dy beyond i64::MAX / 2 still give a shallow line
+    let shallow: Vec<Point> = Point::new(i64::MIN, -1)
+        .line(&Point::new(i64::MAX, 1))
+        .take(3)
+        .collect();
+    assert!(shallow.iter().all(|p| p.y == -1));
+    assert_eq!(shallow[2].x, i64::MIN + 2);
+
+    let corner = Point::new(i64::MAX, i64::MIN);
+    let steep: Vec<Point> = Point::new(i64::MAX - 1, i64::MIN + 3)
+        .line(&corner)
+        .collect();
+    assert_eq!(steep.len(), 4);
+    assert_eq!(steep.last(), Some(&corner));
+    assert_eq!(corner.line(&corner).remaining(), 1);
+}
+
+#[test]
+fn test_neighbourhoods() {
+    let point = Point::new(0, 0);
+
+    assert_eq!(point.neighbours_in(&Neighbourhood::VonNeumann).count(), 4);
+    assert_eq!(point.neighbours_in(&Neighbourhood::Moore).count(), 8);
+    assert_eq!(point.neighbours_in(&Neighbourhood::Radius(1)).count(), 8);
+    assert_eq!(point.neighbours_in(&Neighbourhood::Radius(3)).count(), 48);
+    assert!(
+        point
+            .neighbours_in(&Neighbourhood::Radius(2))
+            .all(|n| n != point && n.x.abs() <= 2 && n.y.abs() <= 2)
+    );
+}
+
+#[test]
+fn test_wrap() {
+    let size = Point::new(5, 3);
+
+    assert_eq!(Point::new(7, -1).wrap(&size), Point::new(2, 2));
+    assert_eq!(Point::new(-10, 3).wrap(&size), Point::new(0, 0));
+    assert_eq!(Point::new(4, 2).wrap(&size), Point::new(4, 2));
+    assert!(Point::new(4, 2).is_inside(&size));
+    assert!(!Point::new(5, 0).is_inside(&size));
+    assert!(!Point::new(0, -1).is_inside(&size));
+}
+
+#[test]
+fn test_point_n_neighbours() {
+    let origin = Point3::origin();
+    let neighbours: HashSet<Point3> = origin.neighbours().collect();
+    assert_eq!(neighbours.len(), 26);
+    assert!(!neighbours.contains(&origin));
+    assert!(neighbours.contains(&Point3::new([-1, 1, 1])));
+
+    let direct: Vec<Point3> = origin.direct_neighbours().collect();
+    assert_eq!(direct.len(), 6);
+    assert!(direct.iter().all(|p| p.distance_squared(&origin) == 1));
+
+    assert_eq!(PointN::<4>::origin().neighbours().count(), 80);
+    assert_eq!(PointN::<1>::new([5]).neighbours().count(), 2);
+}
+
+#[test]
+fn test_point_n_matches_point() {
+    let point = Point::new(3, -2);
+    let point2 = Point2::from(point);
+    assert_eq!((point2.x(), point2.y()), (3, -2));
+    assert_eq!(Point::from(point2), point);
+
+    let mut expected: Vec<Point> = point.neighbours().collect();
+    let mut actual: Vec<Point> = point2.neighbours().map(Point::from).collect();
+    expected.sort_by_key(|p| (p.x, p.y));
+    actual.sort_by_key(|p| (p.x, p.y));
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_point_n_arithmetic() {
+    let a = Point3::new([1, 2, 3]);
+    let b = Point3::new([4, -6, 3]);
+
+    assert_eq!(a + b, Point3::new([5, -4, 6]));
+    assert_eq!(b - a, Point3::new([3, -8, 0]));
+    assert_eq!((b.x(), b.y(), b.z()), (4, -6, 3));
+    assert_eq!(a.distance_squared(&b), 9 + 64);
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Point::new(3, -2);
+    let b = Point::new(-1, 5);
+
+    assert_eq!(a - b, Point::new(4, -7));
+    assert_eq!(-a, Point::new(-3, 2));
+    assert_eq!(a * 3, Point::new(9, -6));
+    // truncates toward zero like i64 division
+    assert_eq!(Point::new(-3, 7) / 2, Point::new(-1, 3));
+
+    let mut c = a;
+    c += b;
+    assert_eq!(c, Point::new(2, 3));
+    c -= b;
+    assert_eq!(c, a);
+
+    assert_eq!([a, b, a].iter().sum::<Point>(), Point::new(5, 1));
+    assert_eq!(
+        Vec::<Point>::new().into_iter().sum::<Point>(),
+        Point::new(0, 0)
+    );
+}
+
+#[test]
+fn test_distances() {
+    let a = Point::new(1, -2);
+    let b = Point::new(-3, 4);
+
+    assert_eq!(a.manhattan(&b), 10);
+    assert_eq!(a.chebyshev(&b), 6);
+    assert_eq!(a.distance_squared(&b), 16 + 36);
+    assert_eq!(a.manhattan(&a), 0);
+
+    // the whole i64 range on one axis still fits in the u64 result
+    let min = Point::new(i64::MIN, 0);
+    let max = Point::new(i64::MAX, i64::MAX);
+    assert_eq!(min.chebyshev(&max), u64::MAX);
+    assert_eq!(min.manhattan(&Point::new(i64::MAX, 0)), u64::MAX);
+
+    let corner = Point::new(i64::MIN, i64::MIN);
+    assert_eq!(corner.chebyshev(&max), u64::MAX);
+    assert_eq!(corner.chebyshev(&corner), 0);
+    assert_eq!(corner.manhattan(&Point::new(i64::MIN + 1, -1)), 1 << 63);
+
+    // squares fit up to 2^32 - 1 per axis
+    let far = Point::new((1 << 32) - 1, 0);
+    assert_eq!(
+        Point::new(0, 0).distance_squared(&far),
+        ((1 << 32) - 1) * ((1 << 32) - 1)
+    );
+    assert_eq!(corner.distance_squared(&(corner + Point::new(3, 4))), 25);
+    assert_eq!(
+        Point3::new([i64::MIN, 0, i64::MAX]).distance_squared(&Point3::new([
+            i64::MIN + 2,
+            0,
+            i64::MAX
+        ])),
+        4
+    );
+}
+
+#[test]
+#[should_panic(expected = "Distance overflows u64")]
+fn test_manhattan_overflow() {
+    Point::new(i64::MIN, i64::MIN).manhattan(&Point::new(i64::MAX, i64::MAX));
+}
+
+#[test]
+#[should_panic(expected = "Distance overflows u64")]
+fn test_distance_squared_overflow() {
+    Point::new(0, 0).distance_squared(&Point::new(1 << 32, 0));
+}
+
+#[test]
+#[should_panic(expected = "Distance overflows u64")]
+fn test_point_n_distance_squared_overflow() {
+    Point3::new([i64::MIN, 0, 0]).distance_squared(&Point3::new([i64::MAX, 0, 0]));
+}
+
+#[test]
+fn test_signum_and_rotation() {
+    assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+    assert_eq!(Point::new(4, -9).signum(), Point::new(1, -1));
+
+    // y grows downwards, so clockwise goes from east to south
+    let east = Point::new(1, 0);
+    assert_eq!(east.rotate_clockwise(), Point::new(0, 1));
+    assert_eq!(east.rotate_counterclockwise(), Point::new(0, -1));
+
+    let p = Point::new(3, -5);
+    assert_eq!(p.rotate_clockwise().rotate_counterclockwise(), p);
+    assert_eq!(p.rotate_clockwise().rotate_clockwise(), -p);
+    assert_eq!(
+        (0..4).fold(p, |p, _| p.rotate_clockwise()),
+        p,
+        "four quarter turns are a full turn"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn test_add_overflow() {
+    let _ = Point::new(i64::MAX, 0) + Point::new(1, 0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn test_neg_overflow() {
+    let _ = -Point::new(i64::MIN, 0);
+}
+
+#[test]
+#[should_panic(expected = "divide by zero")]
+fn test_div_by_zero() {
+    let _ = Point::new(1, 1) / 0;
+}
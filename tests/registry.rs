@@ -0,0 +1,21 @@
+use aoc2025::{Task, TaskInput, find, registry, tasks::y2025::day7};
+
+#[test]
+fn test_registry_lists_2025() {
+    let days: Vec<u32> = registry()
+        .filter(|r| r.year == 2025)
+        .map(|r| r.day)
+        .collect();
+    assert_eq!(days, (1..=10).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_run_through_registry() {
+    let input = ".S.\n...\n.^.\n...\n";
+
+    let registration = find(2025, 7).expect("day 7 is registered");
+    assert_eq!(registration.task.run_str(input, 1), "1");
+
+    let task = day7::Task {};
+    assert_eq!(task.part2(day7::Field::from_str(input)), "2");
+}